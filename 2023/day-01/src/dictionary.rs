@@ -0,0 +1,440 @@
+use std::{
+    char::ToLowercase,
+    collections::VecDeque,
+    str::{CharIndices, FromStr},
+};
+
+/// The digits, which every dictionary starts with.
+const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// The zero of every run of ten decimal digits in Unicode 15.0, in order.
+const UNICODE_ZEROS: [u32; 68] = [
+    0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+    0xDE6, 0xE50, 0xED0, 0xF20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90,
+    0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10,
+    0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650,
+    0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E950,
+    0x1FBF0,
+];
+
+/// The value of `c` if it is a decimal digit in any script.
+fn unicode_digit(c: char) -> Option<u32> {
+    let zero = match UNICODE_ZEROS.binary_search(&(c as u32)) {
+        Ok(i) => UNICODE_ZEROS[i],
+        Err(0) => return None,
+        Err(i) => UNICODE_ZEROS[i - 1],
+    };
+    Some(c as u32 - zero).filter(|&value| value < 10)
+}
+
+/// How text is read before its words are looked for, the same way for the
+/// words themselves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Folding {
+    /// Words match whatever their case, as in `One` or `ONE`.
+    pub ignore_case: bool,
+    /// Decimal digits of every script count as digits, such as `٣` or `３`.
+    pub unicode_digits: bool,
+}
+
+impl Folding {
+    /// The characters `c` reads as, usually just `c`.
+    fn fold(self, c: char) -> Folded {
+        if let Some(value) = self.unicode_digits.then(|| unicode_digit(c)).flatten() {
+            Folded::Char(char::from_digit(value, 10))
+        } else if self.ignore_case {
+            Folded::Lower(c.to_lowercase())
+        } else {
+            Folded::Char(Some(c))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Folded {
+    Char(Option<char>),
+    Lower(ToLowercase),
+}
+
+impl Iterator for Folded {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            Folded::Char(c) => c.take(),
+            Folded::Lower(lower) => lower.next(),
+        }
+    }
+}
+
+/// A word found in some text, by its byte offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// A state of the automaton: the longest prefix of any word that the text
+/// read so far ends with.
+#[derive(Debug, Clone, Default)]
+struct Node {
+    next: Vec<(char, usize)>,
+    /// The node of the longest proper suffix of this prefix.
+    fail: usize,
+    /// The length in folded characters and value of the word this prefix
+    /// is, if it is one.
+    word: Option<(usize, u32)>,
+    /// The nearest node along the fail links that is a whole word.
+    next_word: Option<usize>,
+}
+
+/// Words that stand for values, found all at once in a single pass over a
+/// text, overlapping ones included, with an Aho-Corasick automaton.
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    /// The words besides the digits, as they were given.
+    words: Vec<(String, u32)>,
+    folding: Folding,
+    nodes: Vec<Node>,
+    /// The length of the longest word in folded characters.
+    longest: usize,
+}
+
+impl Dictionary {
+    /// `words` as well as the digits, or why a value can't be used, as a
+    /// line's calibration value is made of two digits. A word given twice
+    /// means its last value.
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Result<Self, String> {
+        let words = words
+            .into_iter()
+            .map(|(word, value)| {
+                if value > 9 {
+                    return Err(format!("{value} isn't a digit from 0 to 9"));
+                }
+                Ok((word.to_owned(), value))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Dictionary::build(words, Folding::default()))
+    }
+
+    /// The same words, matched after `folding` text and words alike.
+    pub fn with_folding(self, folding: Folding) -> Self {
+        Dictionary::build(self.words, folding)
+    }
+
+    fn build(words: Vec<(String, u32)>, folding: Folding) -> Self {
+        let mut nodes = vec![Node::default()];
+        let mut longest = 0;
+        let given = words.iter().map(|(word, value)| (word.as_str(), *value));
+        for (word, value) in DIGITS.into_iter().chain(given) {
+            let mut node = 0;
+            let mut len = 0;
+            for c in word.chars().flat_map(|c| folding.fold(c)) {
+                len += 1;
+                node = match child(&nodes, node, c) {
+                    Some(next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].next.push((c, next));
+                        next
+                    }
+                };
+            }
+            if len > 0 {
+                nodes[node].word = Some((len, value));
+                longest = longest.max(len);
+            }
+        }
+
+        // Breadth first, so every shorter prefix has its links already.
+        let mut queue = VecDeque::from([0]);
+        while let Some(node) = queue.pop_front() {
+            for (c, next) in nodes[node].next.clone() {
+                let fail = if node == 0 {
+                    0
+                } else {
+                    let mut fail = nodes[node].fail;
+                    loop {
+                        if let Some(fail_next) = child(&nodes, fail, c) {
+                            break fail_next;
+                        }
+                        if fail == 0 {
+                            break 0;
+                        }
+                        fail = nodes[fail].fail;
+                    }
+                };
+                nodes[next].fail = fail;
+                nodes[next].next_word = if nodes[fail].word.is_some() {
+                    Some(fail)
+                } else {
+                    nodes[fail].next_word
+                };
+                queue.push_back(next);
+            }
+        }
+        Dictionary {
+            words,
+            folding,
+            nodes,
+            longest,
+        }
+    }
+
+    /// Just the digits.
+    pub fn digits() -> Self {
+        Dictionary::build(Vec::new(), Folding::default())
+    }
+
+    /// The digits and the words from one to nine.
+    pub fn english() -> Self {
+        let words = ENGLISH
+            .into_iter()
+            .map(|(word, value)| (word.to_owned(), value))
+            .collect();
+        Dictionary::build(words, Folding::default())
+    }
+
+    /// Every word in `text`, ordered by where they end and longest first
+    /// where several end at once.
+    pub fn find_all<'a>(&'a self, text: &'a str) -> Matches<'a> {
+        Matches {
+            dictionary: self,
+            chars: text.char_indices(),
+            folded: Folded::Char(None),
+            offset: 0,
+            end: 0,
+            node: 0,
+            starts: VecDeque::with_capacity(self.longest),
+            found: None,
+        }
+    }
+
+    /// The values of the first and last words in `text`. Where two words
+    /// start, or end, at the same place, the longer one counts.
+    pub fn first_and_last(&self, text: &str) -> Option<(u32, u32)> {
+        self.ends(text)
+            .map(|(first, last)| (first.value, last.value))
+    }
+
+    /// The first and last words in `text`, as `first_and_last` picks them.
+    pub fn ends(&self, text: &str) -> Option<(Match, Match)> {
+        let mut first: Option<Match> = None;
+        let mut last: Option<Match> = None;
+        for found in self.find_all(text) {
+            if first.is_none_or(|first| {
+                found.start < first.start || found.start == first.start && found.end > first.end
+            }) {
+                first = Some(found);
+            }
+            if last.is_none_or(|last| found.end > last.end) {
+                last = Some(found);
+            }
+        }
+        Some((first?, last?))
+    }
+
+    fn step(&self, mut node: usize, c: char) -> usize {
+        loop {
+            if let Some(next) = child(&self.nodes, node, c) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+}
+
+/// The words of a text, from `Dictionary::find_all`.
+#[derive(Debug, Clone)]
+pub struct Matches<'a> {
+    dictionary: &'a Dictionary,
+    chars: CharIndices<'a>,
+    /// What is left of the character at `offset..end` once folded.
+    folded: Folded,
+    offset: usize,
+    end: usize,
+    node: usize,
+    /// Where in the text each of the latest folded characters came from.
+    starts: VecDeque<usize>,
+    /// The next word that ends here.
+    found: Option<usize>,
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        let nodes = &self.dictionary.nodes;
+        loop {
+            if let Some(found) = self.found {
+                self.found = nodes[found].next_word;
+                let (len, value) = nodes[found].word.expect("only words are linked");
+                return Some(Match {
+                    start: self.starts[self.starts.len() - len],
+                    end: self.end,
+                    value,
+                });
+            }
+            if let Some(c) = self.folded.next() {
+                if self.starts.len() == self.dictionary.longest {
+                    self.starts.pop_front();
+                }
+                self.starts.push_back(self.offset);
+                self.node = self.dictionary.step(self.node, c);
+                self.found = match nodes[self.node].word {
+                    Some(_) => Some(self.node),
+                    None => nodes[self.node].next_word,
+                };
+                continue;
+            }
+            let (offset, c) = self.chars.next()?;
+            self.offset = offset;
+            self.end = offset + c.len_utf8();
+            self.folded = self.dictionary.folding.fold(c);
+        }
+    }
+}
+
+fn child(nodes: &[Node], node: usize, c: char) -> Option<usize> {
+    nodes[node]
+        .next
+        .iter()
+        .find(|&&(label, _)| label == c)
+        .map(|&(_, next)| next)
+}
+
+/// A comma separated list of `word=value` pairs, each value a single digit,
+/// and the named sets of words, so far only `english`, as in
+/// `english,zero=0`.
+impl FromStr for Dictionary {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut words: Vec<(&str, u32)> = Vec::new();
+        for item in s.split(',').filter(|item| !item.is_empty()) {
+            match item.split_once('=') {
+                Some((word, value)) if !word.is_empty() => {
+                    let value = value
+                        .parse()
+                        .map_err(|_| format!("{value} isn't a digit from 0 to 9"))?;
+                    words.push((word, value));
+                }
+                Some(_) => return Err(format!("{item} has no word")),
+                None if item == "english" => words.extend(ENGLISH),
+                None => return Err(format!("no set of words called {item}")),
+            }
+        }
+        Dictionary::new(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dictionary_test() {
+        let english = Dictionary::english();
+        let values = |text| {
+            english
+                .find_all(text)
+                .map(|found| found.value)
+                .collect::<Vec<u32>>()
+        };
+        assert_eq!(values("eightwothree"), [8, 2, 3]);
+        assert_eq!(values("xtwone3four"), [2, 1, 3, 4]);
+        assert_eq!(values("oneight"), [1, 8]);
+        assert_eq!(values("nothing"), []);
+        assert_eq!(
+            english.find_all("a7two").collect::<Vec<Match>>(),
+            [
+                Match {
+                    start: 1,
+                    end: 2,
+                    value: 7
+                },
+                Match {
+                    start: 2,
+                    end: 5,
+                    value: 2
+                }
+            ]
+        );
+        assert_eq!(english.first_and_last("zoneight234"), Some((1, 4)));
+        assert_eq!(english.first_and_last("7pqrstsixteen"), Some((7, 6)));
+        assert_eq!(english.first_and_last("abc"), None);
+
+        let zero: Dictionary = "english,zero=0,nought=0".parse().unwrap();
+        assert_eq!(zero.first_and_last("noughtzero"), Some((0, 0)));
+        assert_eq!(zero.first_and_last("xzeroneight"), Some((0, 8)));
+        let german: Dictionary = "eins=1,zwei=2,drei=3".parse().unwrap();
+        assert_eq!(german.first_and_last("zweinsdrei"), Some((2, 3)));
+        assert!("english,=3".parse::<Dictionary>().is_err());
+        assert!("french".parse::<Dictionary>().is_err());
+        assert!("one=uno".parse::<Dictionary>().is_err());
+        assert_eq!(
+            "seventeen=17".parse::<Dictionary>().unwrap_err(),
+            "17 isn't a digit from 0 to 9"
+        );
+        assert!("big=4000000000".parse::<Dictionary>().is_err());
+        assert_eq!(
+            Dictionary::new([("ten", 10)]).unwrap_err(),
+            "10 isn't a digit from 0 to 9"
+        );
+        assert!(Dictionary::new([("big", u32::MAX)]).is_err());
+
+        let folded = Dictionary::english().with_folding(Folding {
+            ignore_case: true,
+            unicode_digits: true,
+        });
+        assert_eq!(folded.first_and_last("ThreeXX٣"), Some((3, 3)));
+        assert_eq!(folded.first_and_last("ｘ７EiGHT"), Some((7, 8)));
+        assert_eq!(
+            folded.ends("Ⅸ𝟗nINE"),
+            Some((
+                Match {
+                    start: 3,
+                    end: 7,
+                    value: 9
+                },
+                Match {
+                    start: 7,
+                    end: 11,
+                    value: 9
+                }
+            ))
+        );
+        assert_eq!(english.first_and_last("One٣"), None);
+        assert_eq!(unicode_digit('٩'), Some(9));
+        assert_eq!(unicode_digit('Ⅸ'), None);
+    }
+}
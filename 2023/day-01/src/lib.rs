@@ -0,0 +1,80 @@
+pub mod dictionary;
+pub mod part1;
+pub mod part2;
+pub mod reader;
+
+use aoc_common::{params::ParamError, Param, Params};
+use dictionary::Dictionary;
+use reader::Report;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line with no digit to calibrate from, numbered from 1.
+    NoDigit { line: usize, content: String },
+    /// The input couldn't be read at a line, such as for not being UTF-8.
+    Read { line: usize, reason: String },
+    /// The calibration sum doesn't fit in a `u64` once the line is added.
+    Overflow { line: usize },
+    /// A parameter's value isn't one the solution can use.
+    Param(ParamError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NoDigit { line, content } => write!(f, "line {line} has no digit: {content:?}"),
+            Error::Read { line, reason } => write!(f, "can't read line {line}: {reason}"),
+            Error::Overflow { line } => write!(f, "the calibration sum overflows at line {line}"),
+            Error::Param(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Param(err)
+    }
+}
+
+const ON_MISSING: Param = Param {
+    name: "on-missing",
+    default: "fail",
+    about: "what to do with a line with no digit: fail, or skip it with a warning",
+};
+
+const UNICODE_DIGITS: Param = Param {
+    name: "unicode-digits",
+    default: "false",
+    about: "whether decimal digits of every script count, such as ٣ or ３",
+};
+
+const EXPLAIN: Param = Param {
+    name: "explain",
+    default: "false",
+    about: "whether to print which words give each line's value",
+};
+
+/// The lines of the calibration document.
+fn parse_document(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+/// The calibration sum of `document` with the words and the `on-missing`
+/// and `explain` parameters a part runs with. Warnings and explanations go
+/// to stderr.
+fn calibration_sum(document: &[&str], words: &Dictionary, params: &Params) -> Result<u64, Error> {
+    let explain: bool = params.get(EXPLAIN.name)?;
+    reader::calibrate_lines(
+        document.iter().copied(),
+        words,
+        params.get(ON_MISSING.name)?,
+        |report| match report {
+            Report::Calibrated(explanation) if explain => eprintln!("{explanation}"),
+            Report::Calibrated(_) => {}
+            Report::Skipped(warning) => eprintln!("warning: {warning}"),
+        },
+    )
+}
@@ -0,0 +1,52 @@
+use crate::{
+    dictionary::{Dictionary, Folding},
+    Error,
+};
+use aoc_common::{Param, Params, Solution};
+
+pub fn part1(input: &str) -> Result<u64, Error> {
+    Part1::run(input, &Params::defaults(Part1::PARAMS))
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const PARAMS: &'static [Param] = &[crate::UNICODE_DIGITS, crate::ON_MISSING, crate::EXPLAIN];
+
+    type Input<'a> = Vec<&'a str>;
+    type Output = u64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Error> {
+        Ok(crate::parse_document(input))
+    }
+
+    fn solve(document: Vec<&str>, params: &Params) -> Result<u64, Error> {
+        let digits = Dictionary::digits().with_folding(Folding {
+            unicode_digits: params.get(crate::UNICODE_DIGITS.name)?,
+            ..Folding::default()
+        });
+        crate::calibration_sum(&document, &digits, params)
+    }
+}
+
+const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(142));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    dictionary::{Dictionary, Folding},
+    Error,
+};
+use aoc_common::{Param, Params, Solution};
+
+pub fn part2(input: &str, words: &Dictionary) -> Result<u64, Error> {
+    crate::calibration_sum(
+        &crate::parse_document(input),
+        words,
+        &Params::defaults(Part2::PARAMS),
+    )
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "words",
+            default: "english",
+            about: "the words counted as well as digits: word=value pairs and sets like english",
+        },
+        Param {
+            name: "ignore-case",
+            default: "false",
+            about: "whether words match whatever their case, as in One or ONE",
+        },
+        crate::UNICODE_DIGITS,
+        crate::ON_MISSING,
+        crate::EXPLAIN,
+    ];
+
+    type Input<'a> = Vec<&'a str>;
+    type Output = u64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Error> {
+        Ok(crate::parse_document(input))
+    }
+
+    fn solve(document: Vec<&str>, params: &Params) -> Result<u64, Error> {
+        let words = params.get::<Dictionary>("words")?.with_folding(Folding {
+            ignore_case: params.get("ignore-case")?,
+            unicode_digits: params.get(crate::UNICODE_DIGITS.name)?,
+        });
+        crate::calibration_sum(&document, &words, params)
+    }
+}
+
+const EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE, &Dictionary::english());
+        assert_eq!(result, Ok(281));
+    }
+}
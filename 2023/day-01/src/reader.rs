@@ -0,0 +1,224 @@
+use crate::{
+    dictionary::{Dictionary, Match},
+    Error,
+};
+use std::{
+    fmt,
+    io::{BufRead, BufReader, Read},
+    str::FromStr,
+};
+
+/// What to do about a line with no digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnMissing {
+    /// Stop with `Error::NoDigit`.
+    Fail,
+    /// Warn about the line and count it as 0.
+    Skip,
+}
+
+impl FromStr for OnMissing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "fail" => Ok(OnMissing::Fail),
+            "skip" => Ok(OnMissing::Skip),
+            _ => Err("expected fail or skip".to_owned()),
+        }
+    }
+}
+
+/// A line that was skipped for having no digit, numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    pub line: usize,
+    pub content: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {} has no digit: {:?}", self.line, self.content)
+    }
+}
+
+/// The words a line's calibration value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub line: usize,
+    pub content: &'a str,
+    pub first: Match,
+    pub last: Match,
+}
+
+impl Explanation<'_> {
+    pub fn value(&self) -> u64 {
+        u64::from(self.first.value) * 10 + u64::from(self.last.value)
+    }
+
+    /// Where `found` is in the line, in characters rather than bytes.
+    pub fn chars(&self, found: Match) -> (usize, usize) {
+        let start = self.content[..found.start].chars().count();
+        (
+            start,
+            start + self.content[found.start..found.end].chars().count(),
+        )
+    }
+}
+
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (first_start, first_end) = self.chars(self.first);
+        let (last_start, last_end) = self.chars(self.last);
+        write!(
+            f,
+            "line {}: {} from {:?} at {first_start}..{first_end} and {:?} at {last_start}..{last_end}",
+            self.line,
+            self.value(),
+            &self.content[self.first.start..self.first.end],
+            &self.content[self.last.start..self.last.end],
+        )
+    }
+}
+
+/// What `calibrate` found on a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Report<'a> {
+    Calibrated(Explanation<'a>),
+    Skipped(Warning),
+}
+
+/// The sum of the calibration values of `lines`, numbered from 1. Each
+/// line is passed to `report` in turn.
+pub fn calibrate_lines<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+    words: &Dictionary,
+    on_missing: OnMissing,
+    mut report: impl FnMut(Report),
+) -> Result<u64, Error> {
+    let mut sum = 0;
+    for (line, content) in (1..).zip(lines) {
+        calibrate_line(&mut sum, line, content, words, on_missing, &mut report)?;
+    }
+    Ok(sum)
+}
+
+/// `calibrate_lines` on the lines of `reader`, read one at a time so the
+/// input never has to fit in memory.
+pub fn calibrate(
+    reader: impl Read,
+    words: &Dictionary,
+    on_missing: OnMissing,
+    mut report: impl FnMut(Report),
+) -> Result<u64, Error> {
+    let mut reader = BufReader::new(reader);
+    let mut buffer = String::new();
+    let mut sum = 0;
+    for line in 1.. {
+        buffer.clear();
+        let read = reader.read_line(&mut buffer).map_err(|err| Error::Read {
+            line,
+            reason: err.to_string(),
+        })?;
+        if read == 0 {
+            break;
+        }
+        let content = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        calibrate_line(&mut sum, line, content, words, on_missing, &mut report)?;
+    }
+    Ok(sum)
+}
+
+/// Adds the calibration value of `content`, the line numbered `line`, to
+/// `sum` and reports it.
+fn calibrate_line(
+    sum: &mut u64,
+    line: usize,
+    content: &str,
+    words: &Dictionary,
+    on_missing: OnMissing,
+    report: &mut impl FnMut(Report),
+) -> Result<(), Error> {
+    match words.ends(content) {
+        Some((first, last)) => {
+            let explanation = Explanation {
+                line,
+                content,
+                first,
+                last,
+            };
+            *sum = sum
+                .checked_add(explanation.value())
+                .ok_or(Error::Overflow { line })?;
+            report(Report::Calibrated(explanation));
+        }
+        None if on_missing == OnMissing::Skip => report(Report::Skipped(Warning {
+            line,
+            content: content.to_owned(),
+        })),
+        None => {
+            return Err(Error::NoDigit {
+                line,
+                content: content.to_owned(),
+            })
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibrate_test() {
+        let input = "two1nine\r\nnothing\nzéro7\n";
+        let english = Dictionary::english();
+        assert_eq!(
+            calibrate(input.as_bytes(), &english, OnMissing::Fail, |_| ()),
+            Err(Error::NoDigit {
+                line: 2,
+                content: "nothing".to_owned()
+            })
+        );
+
+        let mut reports = Vec::new();
+        let sum = calibrate(input.as_bytes(), &english, OnMissing::Skip, |report| {
+            reports.push(match report {
+                Report::Calibrated(explanation) => explanation.to_string(),
+                Report::Skipped(warning) => warning.to_string(),
+            })
+        });
+        assert_eq!(sum, Ok(29 + 77));
+        assert_eq!(
+            reports,
+            [
+                r#"line 1: 29 from "two" at 0..3 and "nine" at 4..8"#,
+                r#"line 2 has no digit: "nothing""#,
+                r#"line 3: 77 from "7" at 4..5 and "7" at 4..5"#,
+            ]
+        );
+
+        let invalid: &[u8] = b"1\n\xff2\n";
+        assert!(matches!(
+            calibrate(invalid, &english, OnMissing::Fail, |_| ()),
+            Err(Error::Read { line: 2, .. })
+        ));
+
+        let german = Dictionary::new([("neun", 9), ("null", 0)]).unwrap();
+        assert_eq!(
+            calibrate(
+                "neun\nxnullneun".as_bytes(),
+                &german,
+                OnMissing::Fail,
+                |_| ()
+            ),
+            Ok(99 + 9)
+        );
+        assert_eq!(
+            calibrate_lines(["neun", "xnullneun"], &german, OnMissing::Fail, |_| ()),
+            Ok(99 + 9)
+        );
+    }
+}
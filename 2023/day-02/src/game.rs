@@ -0,0 +1,252 @@
+use crate::Error;
+use aoc_common::parse::{
+    delimited, lines, pair, parse, separated, tag, terminated, unsigned, word, Parser,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+/// Numbers of cubes by colour, as shown in a draw or held in a bag. A
+/// colour that isn't there counts as none, and colours with none aren't
+/// kept, so that cubes are equal whichever way they say so.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+impl Cubes {
+    /// The cubes of `counts`, or the first colour given twice.
+    pub fn new<'a>(counts: impl IntoIterator<Item = (u32, &'a str)>) -> Result<Self, String> {
+        let mut cubes = Cubes::default();
+        let mut seen = BTreeSet::new();
+        for (count, colour) in counts {
+            if !seen.insert(colour) {
+                return Err(colour.to_owned());
+            }
+            if count > 0 {
+                cubes.counts.insert(colour.to_owned(), count);
+            }
+        }
+        Ok(cubes)
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Each colour there is any of and its count, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(colour, &count)| (colour.as_str(), count))
+    }
+
+    /// Whether `other` could all have been taken from these.
+    pub fn holds(&self, other: &Cubes) -> bool {
+        other
+            .iter()
+            .all(|(colour, count)| count <= self.get(colour))
+    }
+
+    /// Raises each colour to at least as many as `other` has.
+    pub fn include(&mut self, other: &Cubes) {
+        for (colour, count) in other.iter() {
+            let slot = self.counts.entry(colour.to_owned()).or_insert(0);
+            *slot = count.max(*slot);
+        }
+    }
+
+    /// The numbers of each of `colours` multiplied together, or `None` if
+    /// that doesn't fit in a `u64`.
+    pub fn power<'a>(&self, colours: impl IntoIterator<Item = &'a str>) -> Option<u64> {
+        colours.into_iter().try_fold(1u64, |power, colour| {
+            power.checked_mul(u64::from(self.get(colour)))
+        })
+    }
+}
+
+/// Cubes written the way a draw is, as in `12 red, 13 green, 14 blue`.
+impl FromStr for Cubes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let counts = parse(s, counts()).map_err(|err| err.to_string())?;
+        Cubes::new(counts).map_err(|colour| format!("{colour} is given twice"))
+    }
+}
+
+/// Some colours, each given once, written separated by spaces as in
+/// `red green blue`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Colours(Vec<String>);
+
+impl Colours {
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+impl FromStr for Colours {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut colours = Vec::new();
+        for colour in s.split_whitespace() {
+            if colours.iter().any(|seen| seen == colour) {
+                return Err(format!("{colour} is given twice"));
+            }
+            colours.push(colour.to_owned());
+        }
+        Ok(Colours(colours))
+    }
+}
+
+/// The cubes shown in one draw from the bag.
+pub type Draw = Cubes;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    /// Whether every draw could have come from `bag`.
+    pub fn is_possible(&self, bag: &Cubes) -> bool {
+        self.draws.iter().all(|draw| bag.holds(draw))
+    }
+
+    /// The fewest cubes of each colour the bag could have held.
+    pub fn minimal_bag(&self) -> Cubes {
+        let mut bag = Cubes::default();
+        for draw in &self.draws {
+            bag.include(draw);
+        }
+        bag
+    }
+}
+
+fn counts<'a>() -> impl Parser<'a, Vec<(u32, &'a str)>> {
+    separated(pair(terminated(unsigned(), tag(" ")), word()), tag(", "))
+}
+
+/// The games of the log, one per line.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, Error> {
+    let game = pair(
+        delimited(tag("Game "), unsigned(), tag(": ")),
+        separated(counts(), tag("; ")),
+    );
+    let mut ids = BTreeSet::new();
+    parse(input, lines(game))?
+        .into_iter()
+        .map(|(id, draws)| {
+            if !ids.insert(id) {
+                return Err(Error::RepeatedGame(id));
+            }
+            let draws = draws
+                .into_iter()
+                .map(|counts| {
+                    Cubes::new(counts).map_err(|colour| Error::RepeatedColour { game: id, colour })
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Game { id, draws })
+        })
+        .collect()
+}
+
+/// The games that could have been played with `bag`.
+pub fn possible<'a>(games: &'a [Game], bag: &'a Cubes) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(|game| game.is_possible(bag))
+}
+
+/// Every colour any game shows.
+pub fn colours(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| &game.draws)
+        .flat_map(|draw| draw.iter().map(|(colour, _)| colour))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_test() {
+        let games = parse_games(
+            "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 7: 2 purple; 1 red, 1 purple",
+        )
+        .unwrap();
+        assert_eq!(games[1].id, 7);
+        assert_eq!(games[1].draws[1].get("purple"), 1);
+        assert_eq!(games[1].draws[1].get("blue"), 0);
+        assert_eq!(
+            colours(&games).into_iter().collect::<Vec<&str>>(),
+            ["blue", "green", "purple", "red"]
+        );
+
+        let minimal = games[0].minimal_bag();
+        assert_eq!(minimal, "4 red, 2 green, 6 blue".parse().unwrap());
+        assert_eq!(minimal.power(["red", "green", "blue"]), Some(48));
+        assert_eq!(minimal.power(["red", "purple"]), Some(0));
+        let huge: Cubes = "4294967295 red, 4294967295 blue, 2 green".parse().unwrap();
+        assert_eq!(huge.power(["red", "blue"]), Some(18446744065119617025));
+        assert_eq!(huge.power(["red", "blue", "green"]), None);
+
+        let colours: Colours = " red  green blue".parse().unwrap();
+        assert_eq!(
+            colours.iter().collect::<Vec<&str>>(),
+            ["red", "green", "blue"]
+        );
+        assert_eq!(
+            "red green red".parse::<Colours>(),
+            Err("red is given twice".to_owned())
+        );
+
+        let bag: Cubes = "12 red, 13 green, 14 blue".parse().unwrap();
+        let ids = |bag: &Cubes| {
+            possible(&games, bag)
+                .map(|game| game.id)
+                .collect::<Vec<u32>>()
+        };
+        assert_eq!(ids(&bag), [1]);
+        assert_eq!(
+            ids(&"4 red, 2 green, 6 blue, 2 purple".parse().unwrap()),
+            [1, 7]
+        );
+        assert_eq!(ids(&Cubes::default()), []);
+
+        assert_eq!(
+            parse_games("Game 1: 1 red, 2 red"),
+            Err(Error::RepeatedColour {
+                game: 1,
+                colour: "red".to_owned()
+            })
+        );
+        assert_eq!(
+            parse_games("Game 1: 1 red\nGame 1: 2 red"),
+            Err(Error::RepeatedGame(1))
+        );
+        assert!(matches!(
+            parse_games("Game 1: red"),
+            Err(Error::Parse(err)) if err.expected == "a number"
+        ));
+        assert_eq!(
+            "1 red, 2 red".parse::<Cubes>(),
+            Err("red is given twice".to_owned())
+        );
+        assert_eq!(
+            "0 red, 2 red".parse::<Cubes>(),
+            Err("red is given twice".to_owned())
+        );
+        assert_eq!(
+            "1 red, 0 blue".parse(),
+            Ok(Cubes::new([(1, "red")]).unwrap())
+        );
+        assert_eq!("0 purple".parse(), Ok(Cubes::default()));
+    }
+}
@@ -0,0 +1,51 @@
+pub mod game;
+pub mod part1;
+pub mod part2;
+pub mod region;
+
+use aoc_common::{params::ParamError, parse::ParseError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A draw shows cubes of the same colour twice.
+    RepeatedColour {
+        game: u32,
+        colour: String,
+    },
+    /// Two games have the same id.
+    RepeatedGame(u32),
+    /// A parameter's value isn't one the solution can use.
+    Param(ParamError),
+    /// The powers of the bags don't fit in a `u64`.
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::RepeatedColour { game, colour } => {
+                write!(f, "a draw of game {game} shows {colour} twice")
+            }
+            Error::RepeatedGame(id) => write!(f, "there are two games {id}"),
+            Error::Param(err) => err.fmt(f),
+            Error::Overflow => write!(f, "the powers overflow"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Param(err)
+    }
+}
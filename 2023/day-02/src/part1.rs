@@ -0,0 +1,58 @@
+use crate::{
+    game::{self, Cubes, Game},
+    Error,
+};
+use aoc_common::{Param, Params, Solution};
+
+pub fn part1(input: &str, bag: &Cubes) -> Result<u32, Error> {
+    Ok(id_sum(&game::parse_games(input)?, bag))
+}
+
+/// The sum of the ids of the games that could have been played with `bag`.
+fn id_sum(games: &[Game], bag: &Cubes) -> u32 {
+    game::possible(games, bag).map(|game| game.id).sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const PARAMS: &'static [Param] = &[Param {
+        name: "bag",
+        default: "12 red, 13 green, 14 blue",
+        about: "the cubes in the bag, written like a draw",
+    }];
+
+    type Input<'a> = Vec<Game>;
+    type Output = u32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Game>, Error> {
+        game::parse_games(input)
+    }
+
+    fn solve(games: Vec<Game>, params: &Params) -> Result<u32, Error> {
+        Ok(id_sum(&games, &params.get("bag")?))
+    }
+}
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE, &"12 red, 13 green, 14 blue".parse().unwrap());
+        assert_eq!(result, Ok(8));
+    }
+}
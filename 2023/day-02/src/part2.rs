@@ -0,0 +1,70 @@
+use crate::{
+    game::{self, Colours, Game},
+    Error,
+};
+use aoc_common::{Param, Params, Solution};
+
+pub fn part2(input: &str, colours: &Colours) -> Result<u64, Error> {
+    total_power(&game::parse_games(input)?, colours)
+}
+
+/// The sum of the powers of the minimal bags of `games`.
+fn total_power(games: &[Game], colours: &Colours) -> Result<u64, Error> {
+    games.iter().try_fold(0u64, |sum, game| {
+        let power = game.minimal_bag().power(colours.iter());
+        power
+            .and_then(|power| sum.checked_add(power))
+            .ok_or(Error::Overflow)
+    })
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const PARAMS: &'static [Param] = &[Param {
+        name: "colours",
+        default: "red green blue",
+        about: "the colours whose numbers make up a bag's power, each once, separated by spaces",
+    }];
+
+    type Input<'a> = Vec<Game>;
+    type Output = u64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Game>, Error> {
+        game::parse_games(input)
+    }
+
+    fn solve(games: Vec<Game>, params: &Params) -> Result<u64, Error> {
+        total_power(&games, &params.get("colours")?)
+    }
+}
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE, &"red green blue".parse().unwrap());
+        assert_eq!(result, Ok(2286));
+        let result = part2(EXAMPLE, &"red".parse().unwrap());
+        assert_eq!(result, Ok(45));
+        let result = part2(
+            "Game 1: 4294967295 red, 4294967295 blue\nGame 2: 4294967295 red, 4294967295 blue",
+            &"red blue".parse().unwrap(),
+        );
+        assert_eq!(result, Err(Error::Overflow));
+    }
+}
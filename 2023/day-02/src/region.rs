@@ -0,0 +1,84 @@
+use crate::game::{Cubes, Game};
+use std::collections::BTreeMap;
+
+/// The fewest cubes of each colour a bag could have held for all of
+/// `games`. The bags they could all have been played with are exactly the
+/// ones that hold these.
+pub fn minimal_bag<'a>(games: impl IntoIterator<Item = &'a Game>) -> Cubes {
+    let mut bag = Cubes::default();
+    for game in games {
+        bag.include(&game.minimal_bag());
+    }
+    bag
+}
+
+/// For each colour, the ids of the games that need the most cubes of it, so
+/// that the minimal bag of `games` would shrink only without all of them.
+/// Colours no game needs any of are left out.
+pub fn binding_games(games: &[Game]) -> BTreeMap<&str, Vec<u32>> {
+    let bag = minimal_bag(games);
+    let mut binding: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+    for game in games {
+        for (colour, count) in game.draws.iter().flat_map(|draw| draw.iter()) {
+            if count == bag.get(colour) {
+                let ids = binding.entry(colour).or_default();
+                if ids.last() != Some(&game.id) {
+                    ids.push(game.id);
+                }
+            }
+        }
+    }
+    binding
+}
+
+/// The ids of the fewest games to leave out for the rest to have been
+/// possible with `bag`. Whether a game fits doesn't depend on the others, so
+/// these are just the ones that don't.
+pub fn games_to_drop(games: &[Game], bag: &Cubes) -> Vec<u32> {
+    games
+        .iter()
+        .filter(|game| !game.is_possible(bag))
+        .map(|game| game.id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::parse_games;
+
+    #[test]
+    fn region_test() {
+        let games = parse_games(
+            "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green; 0 purple",
+        )
+        .unwrap();
+        assert_eq!(
+            minimal_bag(&games),
+            "20 red, 13 green, 15 blue".parse().unwrap()
+        );
+        assert_eq!(
+            minimal_bag(games.iter().filter(|game| game.id != 3)),
+            "14 red, 3 green, 15 blue".parse().unwrap()
+        );
+        assert_eq!(minimal_bag([]), Cubes::default());
+
+        assert_eq!(
+            binding_games(&games),
+            BTreeMap::from([("blue", vec![4]), ("green", vec![3]), ("red", vec![3])])
+        );
+        assert_eq!(
+            binding_games(&[games[1].clone(), games[4].clone()]),
+            BTreeMap::from([("blue", vec![2]), ("green", vec![2, 5]), ("red", vec![5])])
+        );
+
+        let bag = "12 red, 13 green, 14 blue".parse().unwrap();
+        assert_eq!(games_to_drop(&games, &bag), [3, 4]);
+        assert_eq!(games_to_drop(&games, &minimal_bag(&games)), []);
+    }
+}
@@ -0,0 +1,102 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{Grid, Position};
+use std::{fmt, ops::Range};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A row of the schematic that isn't as wide as the first. Lines are
+    /// numbered from 1.
+    RaggedRow {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// A part number too large to add up.
+    NumberTooLarge { line: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::RaggedRow {
+                line,
+                width,
+                expected,
+            } => write!(f, "line {line} is {width} wide instead of {expected}"),
+            Error::NumberTooLarge { line } => write!(f, "line {line} has too large a number"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Checks the schematic is a rectangle, so looking around a number never
+/// falls off a short row.
+fn check_schematic(input: &str) -> Result<(), Error> {
+    let mut rows = input.lines().map(|row| row.chars().count());
+    let expected = rows.next().unwrap_or(0);
+    match rows.position(|width| width != expected) {
+        Some(index) => Err(Error::RaggedRow {
+            line: index + 2,
+            width: input.lines().nth(index + 1).unwrap().chars().count(),
+            expected,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// A part number and where it is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+impl Number {
+    /// The tiles around each of the number's digits, some more than once.
+    fn neighbors<'a>(&self, grid: &'a Grid<char>) -> impl Iterator<Item = Position> + 'a {
+        let row = self.row;
+        self.cols
+            .clone()
+            .flat_map(move |col| grid.neighbors8(Position::new(row, col)))
+    }
+}
+
+/// The engine schematic, with the numbers written on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<Number>,
+}
+
+fn parse_schematic(input: &str) -> Result<Schematic, Error> {
+    check_schematic(input)?;
+    let width = input.lines().next().map_or(0, |row| row.chars().count());
+    let grid = Grid::from_cells(width, input.lines().flat_map(str::chars).collect());
+    let mut numbers = Vec::new();
+    for (row, tiles) in grid.rows().enumerate() {
+        let mut col = 0;
+        while col < width {
+            let len = tiles[col..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if len == 0 {
+                col += 1;
+                continue;
+            }
+            let cols = col..col + len;
+            let value = tiles[cols.clone()]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .map_err(|_| Error::NumberTooLarge { line: row + 1 })?;
+            numbers.push(Number { value, row, cols });
+            col += len;
+        }
+    }
+    Ok(Schematic { grid, numbers })
+}
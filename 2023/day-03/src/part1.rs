@@ -0,0 +1,65 @@
+use crate::{parse_schematic, Error, Schematic};
+use aoc_common::{Params, Solution};
+
+pub fn part1(input: &str) -> Result<u32, Error> {
+    Ok(part_number_sum(&parse_schematic(input)?))
+}
+
+/// The sum of the numbers next to a symbol, diagonally included.
+fn part_number_sum(schematic: &Schematic) -> u32 {
+    let grid = &schematic.grid;
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| {
+            number
+                .neighbors(grid)
+                .any(|position| !grid[position].is_ascii_digit() && grid[position] != '.')
+        })
+        .map(|number| number.value)
+        .sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Schematic;
+    type Output = u32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Schematic, Error> {
+        parse_schematic(input)
+    }
+
+    fn solve(schematic: Schematic, _: &Params) -> Result<u32, Error> {
+        Ok(part_number_sum(&schematic))
+    }
+}
+
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(4361));
+    }
+}
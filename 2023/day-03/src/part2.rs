@@ -0,0 +1,71 @@
+use crate::{parse_schematic, Error, Schematic};
+use aoc_common::{Params, Position, Solution};
+use std::collections::HashMap;
+
+pub fn part2(input: &str) -> Result<u32, Error> {
+    Ok(gear_ratio_sum(&parse_schematic(input)?))
+}
+
+/// The sum of the products of the numbers around each `*` that has more than
+/// one. A number next to several counts for the first of them it touches.
+fn gear_ratio_sum(schematic: &Schematic) -> u32 {
+    let grid = &schematic.grid;
+    let mut gear_numbers: HashMap<Position, Vec<u32>> = HashMap::new();
+    for number in &schematic.numbers {
+        if let Some(gear) = number
+            .neighbors(grid)
+            .find(|&position| grid[position] == '*')
+        {
+            gear_numbers.entry(gear).or_default().push(number.value);
+        }
+    }
+    gear_numbers
+        .values()
+        .filter(|numbers| numbers.len() > 1)
+        .map(|numbers| numbers.iter().product::<u32>())
+        .sum()
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Schematic;
+    type Output = u32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Schematic, Error> {
+        parse_schematic(input)
+    }
+
+    fn solve(schematic: Schematic, _: &Params) -> Result<u32, Error> {
+        Ok(gear_ratio_sum(&schematic))
+    }
+}
+
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(467835));
+    }
+}
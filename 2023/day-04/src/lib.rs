@@ -0,0 +1,54 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::parse::{
+    delimited, lines, pair, parse, preceded, separated, spaces, tag, terminated, unsigned,
+    ParseError,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A card, counted from 1, that wins copies of cards the table doesn't
+    /// have.
+    WinsPastEnd {
+        card: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::WinsPastEnd { card } => write!(f, "card {card} wins cards past the last one"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// A card's winning numbers and the numbers it has.
+pub type Card = (Vec<u32>, Vec<u32>);
+
+fn parse_cards(input: &str) -> Result<Vec<Card>, Error> {
+    let numbers = || separated(unsigned(), spaces());
+    let card = preceded(
+        delimited(
+            pair(tag("Card"), spaces()),
+            unsigned::<u32>(),
+            pair(tag(":"), spaces()),
+        ),
+        pair(
+            terminated(numbers(), pair(spaces(), tag("|"))),
+            preceded(spaces(), numbers()),
+        ),
+    );
+    Ok(parse(input, lines(card))?)
+}
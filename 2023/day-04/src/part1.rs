@@ -0,0 +1,61 @@
+use crate::{parse_cards, Card, Error};
+use aoc_common::{Params, Solution};
+
+pub fn part1(input: &str) -> Result<u32, Error> {
+    Ok(points(parse_cards(input)?))
+}
+
+/// The points the cards are worth together.
+fn points(cards: Vec<Card>) -> u32 {
+    cards
+        .into_iter()
+        .filter_map(|(winning, have)| {
+            let match_count = have.iter().filter(|&have| winning.contains(have)).count() as u32;
+            if match_count == 0 {
+                None
+            } else {
+                Some(2u32.pow(match_count - 1))
+            }
+        })
+        .sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Card>;
+    type Output = u32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Card>, Error> {
+        parse_cards(input)
+    }
+
+    fn solve(cards: Vec<Card>, _: &Params) -> Result<u32, Error> {
+        Ok(points(cards))
+    }
+}
+
+const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(13));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{parse_cards, Error};
+use aoc_common::{Params, Solution};
+
+pub fn part2(input: &str) -> Result<u32, Error> {
+    Ok(scratchcards(&parse_matches(input)?))
+}
+
+/// How many winning numbers each card has, checked not to win copies of
+/// cards past the end of the table.
+fn parse_matches(input: &str) -> Result<Vec<usize>, Error> {
+    let card_matches: Vec<usize> = parse_cards(input)?
+        .into_iter()
+        .map(|(winning, have)| have.iter().filter(|&have| winning.contains(have)).count())
+        .collect();
+    match (0..card_matches.len()).find(|&card| card + card_matches[card] >= card_matches.len()) {
+        Some(card) => Err(Error::WinsPastEnd { card: card + 1 }),
+        None => Ok(card_matches),
+    }
+}
+
+/// How many scratchcards the cards win, themselves included.
+fn scratchcards(card_matches: &[usize]) -> u32 {
+    let mut queue: Vec<usize> = (0..card_matches.len()).collect();
+    let mut scratchcards: u32 = card_matches.len() as u32;
+    while let Some(curr) = queue.pop() {
+        scratchcards += card_matches[curr] as u32;
+        for next in curr + 1..=curr + card_matches[curr] {
+            queue.push(next);
+        }
+    }
+    scratchcards
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<usize>;
+    type Output = u32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<usize>, Error> {
+        parse_matches(input)
+    }
+
+    fn solve(card_matches: Vec<usize>, _: &Params) -> Result<u32, Error> {
+        Ok(scratchcards(&card_matches))
+    }
+}
+
+const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(30));
+    }
+}
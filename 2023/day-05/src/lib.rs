@@ -0,0 +1,33 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::parse::ParseError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// Seed ranges come as a start and a length, but the last start has no
+    /// length.
+    UnpairedSeed,
+    /// No seeds to find a location for.
+    NoSeeds,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::UnpairedSeed => write!(f, "the last seed range has no length"),
+            Error::NoSeeds => write!(f, "there are no seeds"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::Error;
+use aoc_common::{
+    interval::{Interval, OffsetMap},
+    parse::{
+        lines, map, pair, parse, preceded, sections, separated, spaces, tag, take_while1,
+        terminated, try_map, unsigned,
+    },
+    Params, Solution,
+};
+
+/// The seeds and the chain of maps, each as the offset it adds to every
+/// source number it covers.
+pub struct Almanac {
+    seeds: Vec<i64>,
+    mappings: Vec<OffsetMap>,
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac, Error> {
+    let numbers = || separated(unsigned::<i64>(), spaces());
+    let entry = try_map(numbers(), |numbers| match numbers[..] {
+        [destination, source, len] => {
+            Ok((Interval::with_len(source, len as u64), destination - source))
+        }
+        _ => Err("three numbers".to_string()),
+    });
+    let name = take_while1("a map name", |c| c.is_alphanumeric() || c == '-');
+    let mapping = preceded(
+        terminated(name, tag(" map:\n")),
+        map(lines(entry), |entries| {
+            let mut mapping = OffsetMap::new();
+            for (source, offset) in entries {
+                mapping.insert(source, offset);
+            }
+            mapping
+        }),
+    );
+    let seeds = terminated(preceded(tag("seeds: "), numbers()), tag("\n\n"));
+    let (seeds, mappings) = parse(input, pair(seeds, sections(mapping)))?;
+    if seeds.is_empty() {
+        return Err(Error::NoSeeds);
+    }
+    Ok(Almanac { seeds, mappings })
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Part1::run(input, &Params::defaults(Part1::PARAMS))
+}
+
+fn lowest_location(almanac: Almanac) -> usize {
+    almanac
+        .seeds
+        .into_iter()
+        .map(|seed| {
+            almanac
+                .mappings
+                .iter()
+                .fold(seed, |source, mapping| mapping.map(source))
+        })
+        .min()
+        .map(|location| location as usize)
+        .expect("parsing checks there are seeds")
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Almanac;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Almanac, Error> {
+        parse_almanac(input)
+    }
+
+    fn solve(almanac: Almanac, _: &Params) -> Result<usize, Error> {
+        Ok(lowest_location(almanac))
+    }
+}
+
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(35));
+    }
+}
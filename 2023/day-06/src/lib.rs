@@ -0,0 +1,39 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::parse::ParseError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// The rows don't give a distance for every time.
+    UnevenRows {
+        times: usize,
+        distances: usize,
+    },
+    /// A race, counted from 1, whose record no button press can beat.
+    Unbeatable {
+        race: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::UnevenRows { times, distances } => {
+                write!(f, "{times} times but {distances} distances")
+            }
+            Error::Unbeatable { race } => write!(f, "race {race}'s record can't be beaten"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
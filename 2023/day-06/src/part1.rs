@@ -0,0 +1,98 @@
+use crate::Error;
+use aoc_common::{
+    parse::{pair, parse, preceded, separated, spaces, tag, terminated, unsigned},
+    Params, Solution,
+};
+
+/// Each race's time and record distance.
+fn parse_races(input: &str) -> Result<Vec<(i32, i32)>, Error> {
+    let row = |name| {
+        preceded(
+            pair(tag(name), spaces()),
+            separated(unsigned::<i32>(), spaces()),
+        )
+    };
+    let (times, distances) = parse(
+        input,
+        pair(terminated(row("Time:"), tag("\n")), row("Distance:")),
+    )?;
+    if times.len() != distances.len() {
+        return Err(Error::UnevenRows {
+            times: times.len(),
+            distances: distances.len(),
+        });
+    }
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+pub fn part1(input: &str) -> Result<u32, Error> {
+    ways_to_win(&parse_races(input)?)
+}
+
+/// The numbers of ways to beat each race's record multiplied together.
+fn ways_to_win(races: &[(i32, i32)]) -> Result<u32, Error> {
+    races
+        .iter()
+        .enumerate()
+        .map(|(race, (time, distance))| {
+            let unbeatable = Error::Unbeatable { race: race + 1 };
+            let disc_squared = time.pow(2) - 4 * distance;
+            if disc_squared < 0 {
+                return Err(unbeatable);
+            }
+            let disc = (disc_squared as f64).sqrt();
+            let x1 = (*time as f64 + disc) / 2.0;
+            let x1 = if x1.fract() == 0.0 {
+                x1 - 1.0
+            } else {
+                x1.floor()
+            } as u32;
+            let x2 = (*time as f64 - disc) / 2.0;
+            let x2 = if x2.fract() == 0.0 {
+                x2 + 1.0
+            } else {
+                x2.ceil()
+            } as u32;
+            (x1 + 1)
+                .checked_sub(x2)
+                .filter(|&ways| ways > 0)
+                .ok_or(unbeatable)
+        })
+        .product()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<(i32, i32)>;
+    type Output = u32;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, Error> {
+        parse_races(input)
+    }
+
+    fn solve(races: Vec<(i32, i32)>, _: &Params) -> Result<u32, Error> {
+        ways_to_win(&races)
+    }
+}
+
+const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(288));
+    }
+}
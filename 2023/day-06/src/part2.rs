@@ -0,0 +1,94 @@
+use crate::Error;
+use aoc_common::{
+    parse::{pair, parse, preceded, separated, spaces, tag, take_while1, terminated, try_map},
+    Params, Solution,
+};
+
+/// The race's time and record distance.
+fn parse_race(input: &str) -> Result<(usize, usize), Error> {
+    // The spaces between the digits don't count.
+    let row = |name| {
+        preceded(
+            pair(tag(name), spaces()),
+            try_map(
+                separated(take_while1("a digit", |c| c.is_ascii_digit()), spaces()),
+                |digits| {
+                    digits
+                        .concat()
+                        .parse::<usize>()
+                        .map_err(|_| "fewer digits".to_string())
+                },
+            ),
+        )
+    };
+    Ok(parse(
+        input,
+        pair(terminated(row("Time:"), tag("\n")), row("Distance:")),
+    )?)
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    ways_to_win(parse_race(input)?)
+}
+
+/// The number of ways to beat the record.
+fn ways_to_win((time, distance): (usize, usize)) -> Result<usize, Error> {
+    let unbeatable = Error::Unbeatable { race: 1 };
+    let disc_squared = time
+        .pow(2)
+        .checked_sub(4 * distance)
+        .ok_or(unbeatable.clone())?;
+    let disc = (disc_squared as f64).sqrt();
+    let x1 = (time as f64 + disc) / 2.0;
+    let x1 = if x1.fract() == 0.0 {
+        x1 - 1.0
+    } else {
+        x1.floor()
+    } as usize;
+    let x2 = (time as f64 - disc) / 2.0;
+    let x2 = if x2.fract() == 0.0 {
+        x2 + 1.0
+    } else {
+        x2.ceil()
+    } as usize;
+    (x1 + 1)
+        .checked_sub(x2)
+        .filter(|&ways| ways > 0)
+        .ok_or(unbeatable)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = (usize, usize);
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<(usize, usize), Error> {
+        parse_race(input)
+    }
+
+    fn solve(race: (usize, usize), _: &Params) -> Result<usize, Error> {
+        ways_to_win(race)
+    }
+}
+
+const EXAMPLE: &str = "\
+Time:      71530
+Distance:  940200";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(71503));
+    }
+}
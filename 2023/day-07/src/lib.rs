@@ -0,0 +1,43 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::parse::ParseError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A character in a hand that isn't one of `23456789TJQKA`.
+    UnknownCard {
+        line: usize,
+        column: usize,
+        card: char,
+    },
+    /// A hand without exactly five cards.
+    HandSize {
+        line: usize,
+        cards: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::UnknownCard { line, column, card } => {
+                write!(f, "{line}:{column}: unknown card {card:?}")
+            }
+            Error::HandSize { line, cards } => {
+                write!(f, "{line}:1: expected 5 cards, found {cards}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::Error;
+use aoc_common::{
+    parse::{lines, pair, parse, spaces, take_while1, terminated, unsigned},
+    Params, Solution,
+};
+use std::iter::zip;
+use std::{cmp::Ordering, collections::HashMap};
+
+#[derive(PartialOrd, PartialEq, Debug)]
+enum HandType {
+    FiveOfAKind = 6,
+    FourOfAKind = 5,
+    FullHouse = 4,
+    ThreeOfAKind = 3,
+    TwoPair = 2,
+    OnePair = 1,
+    HighCard = 0,
+}
+
+/// A hand of cards with its bet, typed when it is parsed.
+#[derive(Debug)]
+pub struct Hand {
+    bet: usize,
+    cards: [Card; 5],
+    hand_type: HandType,
+}
+
+#[derive(PartialEq, Debug, Copy, Clone, Eq, Hash)]
+enum Card {
+    FaceCard(FaceCardType),
+    NumberCard(usize),
+}
+
+impl Card {
+    fn from(c: char) -> Option<Card> {
+        let card = match c {
+            'A' => Card::FaceCard(FaceCardType::Ace),
+            'K' => Card::FaceCard(FaceCardType::King),
+            'Q' => Card::FaceCard(FaceCardType::Queen),
+            'J' => Card::FaceCard(FaceCardType::Joker),
+            'T' => Card::FaceCard(FaceCardType::Ten),
+            '2'..='9' => Card::NumberCard(c.to_digit(10)? as usize),
+            _ => return None,
+        };
+        Some(card)
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Card) -> Option<Ordering> {
+        match (self, other) {
+            (Card::FaceCard(face1), Card::FaceCard(face2)) => match (face1, face2) {
+                (JOKER, JOKER) => Some(Ordering::Equal),
+                (JOKER, _) => Some(Ordering::Less),
+                (_, JOKER) => Some(Ordering::Greater),
+                (_, _) => face1.partial_cmp(face2),
+            },
+            (Card::NumberCard(num1), Card::NumberCard(num2)) => num1.partial_cmp(num2),
+            (Card::FaceCard(face1), Card::NumberCard(_)) => {
+                if face1 == JOKER {
+                    Some(Ordering::Less)
+                } else {
+                    Some(Ordering::Greater)
+                }
+            }
+            (Card::NumberCard(_), Card::FaceCard(face2)) => {
+                if face2 == JOKER {
+                    Some(Ordering::Greater)
+                } else {
+                    Some(Ordering::Less)
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialOrd, PartialEq, Debug, Copy, Clone, Eq, Hash)]
+enum FaceCardType {
+    Ace = 14,
+    King = 13,
+    Queen = 12,
+    Joker = 11,
+    Ten = 10,
+}
+
+const JOKER: &FaceCardType = &FaceCardType::Joker;
+const JOKER_CARD: &Card = &Card::FaceCard(FaceCardType::Joker);
+fn parse_hands(input: &str) -> Result<Vec<Hand>, Error> {
+    let hand = pair(
+        terminated(take_while1("a hand", |c| c != ' '), spaces()),
+        unsigned::<usize>(),
+    );
+    parse(input, lines(hand))?
+        .into_iter()
+        .enumerate()
+        .map(|(index, (cards, bet))| {
+            let line = index + 1;
+            let cards: Vec<Card> = cards
+                .chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    Card::from(c).ok_or(Error::UnknownCard {
+                        line,
+                        column: column + 1,
+                        card: c,
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            let cards: [Card; 5] =
+                cards
+                    .try_into()
+                    .map_err(|cards: Vec<Card>| Error::HandSize {
+                        line,
+                        cards: cards.len(),
+                    })?;
+            let mut card_counts: HashMap<Card, usize> = HashMap::new();
+            for card in cards {
+                *card_counts.entry(card).or_default() += 1;
+            }
+            let jokers = card_counts.remove(JOKER_CARD).unwrap_or(0);
+            let mut card_counts: Vec<usize> = card_counts.into_values().collect();
+            card_counts.sort();
+            if let Some(last_card_count) = card_counts.last_mut() {
+                *last_card_count += jokers;
+            } else {
+                card_counts.push(5);
+            }
+            let mut sorted_card_counts = card_counts.into_iter();
+            let hand_type = match sorted_card_counts.next().unwrap() {
+                5 => HandType::FiveOfAKind,
+                2 => HandType::FullHouse,
+                1 => match sorted_card_counts.next().unwrap() {
+                    4 => HandType::FourOfAKind,
+                    2 => HandType::TwoPair,
+                    1 => match sorted_card_counts.next().unwrap() {
+                        3 => HandType::ThreeOfAKind,
+                        1 => match sorted_card_counts.next().unwrap() {
+                            2 => HandType::OnePair,
+                            1 => HandType::HighCard,
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            Ok(Hand {
+                bet,
+                cards,
+                hand_type,
+            })
+        })
+        .collect()
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(winnings(parse_hands(input)?))
+}
+
+/// The total winnings of the hands, ranked from weakest to strongest.
+fn winnings(mut hands: Vec<Hand>) -> usize {
+    hands.sort_by(
+        |hand1, hand2| match hand1.hand_type.partial_cmp(&hand2.hand_type) {
+            Some(Ordering::Equal) => zip(hand1.cards, hand2.cards)
+                .find_map(|(card1, card2)| match card1.partial_cmp(&card2) {
+                    Some(Ordering::Equal) => None,
+                    Some(ord) => Some(ord),
+                    None => unreachable!(),
+                })
+                .unwrap_or(Ordering::Equal),
+            Some(ord) => ord,
+            None => unreachable!(),
+        },
+    );
+    hands
+        .iter()
+        .enumerate()
+        .fold(0, |sum, (i, hand)| sum + (i + 1) * hand.bet)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Hand>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, Error> {
+        parse_hands(input)
+    }
+
+    fn solve(hands: Vec<Hand>, _: &Params) -> Result<usize, Error> {
+        Ok(winnings(hands))
+    }
+}
+
+const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(5905));
+    }
+}
@@ -0,0 +1,79 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::parse::ParseError;
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A direction that's neither `L` nor `R`, at its column of the first
+    /// line.
+    UnknownDirection {
+        column: usize,
+        direction: char,
+    },
+    /// A node that's named but never given its neighbours.
+    UnknownNode(String),
+    /// No node ends in `A` for a ghost to start on.
+    NoGhosts,
+    /// The ghosts' loops never line up on Z nodes at the same step.
+    GhostsNeverAligned,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::UnknownDirection { column, direction } => {
+                write!(f, "1:{column}: unknown direction {direction:?}")
+            }
+            Error::UnknownNode(node) => write!(f, "node {node} is never defined"),
+            Error::NoGhosts => write!(f, "no node ends in A"),
+            Error::GhostsNeverAligned => write!(f, "the ghosts are never all on Z nodes at once"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// The directions to turn, and each node's left and right neighbours.
+pub struct Network {
+    pub directions: String,
+    pub location_map: HashMap<String, [String; 2]>,
+}
+
+/// Checks every direction is `L` or `R`, and that every node a step can
+/// lead to, or that the walk needs, is defined.
+fn check_network(
+    directions: &str,
+    location_map: &HashMap<String, [String; 2]>,
+    needed: &[&str],
+) -> Result<(), Error> {
+    if let Some((column, direction)) = directions
+        .chars()
+        .enumerate()
+        .find(|&(_, direction)| direction != 'L' && direction != 'R')
+    {
+        return Err(Error::UnknownDirection {
+            column: column + 1,
+            direction,
+        });
+    }
+    let named = location_map.values().flatten().map(String::as_str);
+    match needed
+        .iter()
+        .copied()
+        .chain(named)
+        .find(|node| !location_map.contains_key(*node))
+    {
+        Some(node) => Err(Error::UnknownNode(node.to_string())),
+        None => Ok(()),
+    }
+}
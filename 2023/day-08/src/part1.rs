@@ -0,0 +1,105 @@
+use crate::{check_network, Error, Network};
+use aoc_common::{
+    parse::{delimited, lines, pair, parse, tag, terminated, word, ParseResult},
+    Params, Solution,
+};
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// A node's name and its left and right neighbours.
+type Node<'a> = (&'a str, (&'a str, &'a str));
+
+/// The directions, then every node.
+fn parse_network(input: &str) -> ParseResult<(&str, Vec<Node<'_>>)> {
+    let node = pair(
+        terminated(word(), tag(" = ")),
+        delimited(
+            tag("("),
+            pair(terminated(word(), tag(", ")), word()),
+            tag(")"),
+        ),
+    );
+    parse(input, pair(terminated(word(), tag("\n\n")), lines(node)))
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(steps(&parse_map(input)?))
+}
+
+/// The network, checked to have every node the walk from AAA to ZZZ needs.
+fn parse_map(input: &str) -> Result<Network, Error> {
+    let (directions, nodes) = parse_network(input)?;
+    let location_map: HashMap<String, [String; 2]> = nodes
+        .into_iter()
+        .map(|(node, (left, right))| (node.to_string(), [left.to_string(), right.to_string()]))
+        .collect();
+    check_network(directions, &location_map, &["AAA", "ZZZ"])?;
+    Ok(Network {
+        directions: directions.to_string(),
+        location_map,
+    })
+}
+
+/// The steps it takes to walk from AAA to ZZZ.
+fn steps(network: &Network) -> usize {
+    let Network {
+        directions,
+        location_map,
+    } = network;
+    directions
+        .chars()
+        .cycle()
+        .fold_while((0, "AAA"), |(count, source), c| {
+            if source == "ZZZ" {
+                return Done((count, source));
+            }
+            let dest = &location_map[source];
+            if c == 'R' {
+                Continue((count + 1, &dest[1]))
+            } else {
+                Continue((count + 1, &dest[0]))
+            }
+        })
+        .into_inner()
+        .0
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Network;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Network, Error> {
+        parse_map(input)
+    }
+
+    fn solve(network: Network, _: &Params) -> Result<usize, Error> {
+        Ok(steps(&network))
+    }
+}
+
+const EXAMPLE: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(6));
+    }
+}
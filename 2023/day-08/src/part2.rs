@@ -0,0 +1,130 @@
+use crate::{check_network, Error, Network};
+use aoc_common::{
+    math,
+    parse::{delimited, lines, pair, parse, tag, terminated, word, ParseResult},
+    Params, Solution,
+};
+use std::collections::HashMap;
+
+/// A node's name and its left and right neighbours.
+type Node<'a> = (&'a str, (&'a str, &'a str));
+
+/// The directions, then every node.
+fn parse_network(input: &str) -> ParseResult<(&str, Vec<Node<'_>>)> {
+    let node = pair(
+        terminated(word(), tag(" = ")),
+        delimited(
+            tag("("),
+            pair(terminated(word(), tag(", ")), word()),
+            tag(")"),
+        ),
+    );
+    parse(input, pair(terminated(word(), tag("\n\n")), lines(node)))
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    ghost_steps(&parse_map(input)?)
+}
+
+/// The network, checked to have every node a step can lead to and a node
+/// ending in `A` for a ghost to start on.
+fn parse_map(input: &str) -> Result<Network, Error> {
+    let (directions, nodes) = parse_network(input)?;
+    let location_map: HashMap<String, [String; 2]> = nodes
+        .into_iter()
+        .map(|(node, (left, right))| (node.to_string(), [left.to_string(), right.to_string()]))
+        .collect();
+    check_network(directions, &location_map, &[])?;
+    if !location_map.keys().any(|node| node.ends_with('A')) {
+        return Err(Error::NoGhosts);
+    }
+    Ok(Network {
+        directions: directions.to_string(),
+        location_map,
+    })
+}
+
+/// The steps it takes until every ghost is on a Z node at once.
+fn ghost_steps(network: &Network) -> Result<usize, Error> {
+    let Network {
+        directions,
+        location_map,
+    } = network;
+    let mut ghosts: Vec<&str> = location_map
+        .keys()
+        .map(String::as_str)
+        .filter(|node| node.ends_with('A'))
+        .collect();
+    let mut directions = directions.chars().cycle();
+    // The first two steps each ghost is on a Z node at. Each one's path
+    // loops back round to the same Z node, so it's there again every
+    // (second - first) steps after the first.
+    let mut z_steps: Vec<Vec<usize>> = vec![Vec::new(); ghosts.len()];
+    let mut step = 0;
+    while z_steps.iter().any(|seen| seen.len() < 2) {
+        for (ghost, seen) in ghosts.iter().zip(&mut z_steps) {
+            if ghost.ends_with('Z') && seen.len() < 2 {
+                seen.push(step);
+            }
+        }
+        let i = if directions.next() == Some('R') { 1 } else { 0 };
+        for ghost in ghosts.iter_mut() {
+            *ghost = &location_map[*ghost][i];
+        }
+        step += 1;
+    }
+    let (residue, period) = math::crt(
+        z_steps
+            .iter()
+            .map(|seen| (seen[0] as i64, (seen[1] - seen[0]) as u64)),
+    )
+    .ok_or(Error::GhostsNeverAligned)?;
+    let (residue, period) = (residue as usize, period as usize);
+    // The first step that fits, once every ghost has reached its loop.
+    let first = z_steps.iter().map(|seen| seen[0]).max().unwrap();
+    Ok(first + (residue + period - first % period) % period)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Network;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Network, Error> {
+        parse_map(input)
+    }
+
+    fn solve(network: Network, _: &Params) -> Result<usize, Error> {
+        ghost_steps(&network)
+    }
+}
+
+const EXAMPLE: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(6));
+    }
+}
@@ -1,51 +1,6 @@
-fn main() {
-    let input = include_str!("./input.txt");
-    let output = part2(input);
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = aoc_common::input!()?;
+    let output = day_2023_09::part2::part2(&input)?;
     dbg!(output);
-}
-
-fn interpolate_prev(points: Vec<isize>) -> f64 {
-    let at = -1.0;
-    (0..points.len())
-        .map(|i| {
-            (0..points.len())
-                .map(|j| {
-                    if i == j {
-                        1.0
-                    } else {
-                        (at - j as f64) / (i as f64 - j as f64)
-                    }
-                })
-                .fold(points[i] as f64, |product, v| product * v)
-        })
-        .sum()
-}
-
-fn part2(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| {
-            interpolate_prev(
-                line.split_whitespace()
-                    .map(|c| c.parse::<isize>().unwrap())
-                    .collect(),
-            )
-        })
-        .sum::<f64>()
-        .round() as usize
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part2_test() {
-        let result = part2(
-            "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45",
-        );
-        assert_eq!(result, 2);
-    }
+    Ok(())
 }
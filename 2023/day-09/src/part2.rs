@@ -0,0 +1,76 @@
+use crate::Error;
+use aoc_common::{
+    parse::{lines, parse, separated, signed, spaces},
+    Params, Solution,
+};
+
+fn interpolate_prev(points: Vec<isize>) -> f64 {
+    let at = -1.0;
+    (0..points.len())
+        .map(|i| {
+            (0..points.len())
+                .map(|j| {
+                    if i == j {
+                        1.0
+                    } else {
+                        (at - j as f64) / (i as f64 - j as f64)
+                    }
+                })
+                .fold(points[i] as f64, |product, v| product * v)
+        })
+        .sum()
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(extrapolate(parse_histories(input)?))
+}
+
+/// Each line's history of readings.
+fn parse_histories(input: &str) -> Result<Vec<Vec<isize>>, Error> {
+    Ok(parse(input, lines(separated(signed::<isize>(), spaces())))?)
+}
+
+fn extrapolate(histories: Vec<Vec<isize>>) -> usize {
+    histories
+        .into_iter()
+        .map(interpolate_prev)
+        .sum::<f64>()
+        .round() as usize
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Vec<isize>>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Vec<isize>>, Error> {
+        parse_histories(input)
+    }
+
+    fn solve(histories: Vec<Vec<isize>>, _: &Params) -> Result<usize, Error> {
+        Ok(extrapolate(histories))
+    }
+}
+
+const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(2));
+    }
+}
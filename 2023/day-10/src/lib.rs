@@ -0,0 +1,125 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    parse::{self, ParseError},
+    Direction, Grid, Position,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A tile that's none of `|-LJ7F.S`.
+    UnknownTile {
+        line: usize,
+        column: usize,
+        tile: char,
+    },
+    /// No `S` to start from.
+    MissingStart,
+    /// No pipe next to the start connects to it.
+    StartNotConnected,
+    /// The pipe out of the start ends at this tile instead of coming back.
+    BrokenLoop {
+        line: usize,
+        column: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::UnknownTile { line, column, tile } => {
+                write!(f, "{line}:{column}: unknown tile {tile:?}")
+            }
+            Error::MissingStart => write!(f, "there's no start tile S"),
+            Error::StartNotConnected => write!(f, "no pipe connects to the start"),
+            Error::BrokenLoop { line, column } => {
+                write!(f, "{line}:{column}: the loop breaks off here")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// The sketch of the pipes, where the start is, and every tile of the loop
+/// through it in order, the start first.
+pub struct Sketch {
+    pub grid: Grid<char>,
+    pub start: Position,
+    pub pipe_loop: Vec<Position>,
+}
+
+/// The directions of the two tiles a pipe connects.
+fn connections(tile: char) -> &'static [Direction] {
+    match tile {
+        '|' => &[Direction::Up, Direction::Down],
+        '-' => &[Direction::Left, Direction::Right],
+        'L' => &[Direction::Up, Direction::Right],
+        'J' => &[Direction::Up, Direction::Left],
+        '7' => &[Direction::Down, Direction::Left],
+        'F' => &[Direction::Down, Direction::Right],
+        _ => &[],
+    }
+}
+
+/// Whether the pipe at `position` connects back to where a step
+/// `direction` came from.
+fn connects(grid: &Grid<char>, position: Position, direction: Direction) -> bool {
+    connections(grid[position]).contains(&direction.opposite())
+}
+
+/// Every tile of the loop through `start`, in order.
+fn pipe_loop(grid: &Grid<char>, start: Position) -> Result<Vec<Position>, Error> {
+    let (mut direction, mut curr) = Direction::ALL
+        .into_iter()
+        .find_map(|direction| {
+            let next = grid.step(start, direction)?;
+            connects(grid, next, direction).then_some((direction, next))
+        })
+        .ok_or(Error::StartNotConnected)?;
+    let mut pipe_loop = vec![start];
+    while curr != start {
+        pipe_loop.push(curr);
+        let broken = Error::BrokenLoop {
+            line: curr.row + 1,
+            column: curr.col + 1,
+        };
+        direction = *connections(grid[curr])
+            .iter()
+            .find(|&&next_direction| next_direction != direction.opposite())
+            .ok_or(broken.clone())?;
+        curr = grid
+            .step(curr, direction)
+            .filter(|&next| next == start || connects(grid, next, direction))
+            .ok_or(broken)?;
+    }
+    Ok(pipe_loop)
+}
+
+fn parse_sketch(input: &str) -> Result<Sketch, Error> {
+    let grid = parse::parse(input, parse::grid("a tile", Some))?;
+    if let Some(((row, col), &tile)) = grid.iter().find(|(_, tile)| !"|-LJ7F.S".contains(**tile)) {
+        return Err(Error::UnknownTile {
+            line: row + 1,
+            column: col + 1,
+            tile,
+        });
+    }
+    let start = grid.find(|&c| c == 'S').ok_or(Error::MissingStart)?.into();
+    let pipe_loop = pipe_loop(&grid, start)?;
+    Ok(Sketch {
+        grid,
+        start,
+        pipe_loop,
+    })
+}
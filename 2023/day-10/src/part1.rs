@@ -0,0 +1,60 @@
+use crate::{parse_sketch, Error, Sketch};
+use aoc_common::{Params, Solution};
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(farthest(&parse_sketch(input)?))
+}
+
+/// The steps along the loop to the tile farthest from the start.
+fn farthest(sketch: &Sketch) -> usize {
+    // floor division
+    sketch.pipe_loop.len() / 2
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Sketch;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Sketch, Error> {
+        parse_sketch(input)
+    }
+
+    fn solve(sketch: Sketch, _: &Params) -> Result<usize, Error> {
+        Ok(farthest(&sketch))
+    }
+}
+
+const EXAMPLE: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(4));
+
+        let result = part1(
+            "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...",
+        );
+        assert_eq!(result, Ok(8))
+    }
+}
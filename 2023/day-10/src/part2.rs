@@ -0,0 +1,124 @@
+use crate::{parse_sketch, Error, Sketch};
+use aoc_common::{
+    search::{self, Dense},
+    Direction, Grid, Params, Position, Solution,
+};
+use itertools::Itertools;
+
+/// How many tiles of the original grid are reachable from `from` without
+/// crossing the loop, or `None` if that reaches the edge of the grid, so
+/// `from` is outside the loop.
+fn flood(in_loop: &Grid<bool>, from: Position) -> Option<usize> {
+    let (width, height) = (in_loop.width(), in_loop.height());
+    let flooded = search::bfs_in(
+        Dense::new(width * height, |position: &Position| {
+            position.row * width + position.col
+        }),
+        from,
+        |&position| in_loop.neighbors(position).filter(|&next| !in_loop[next]),
+        |&Position { row, col }| row == 0 || col == 0 || row + 1 == height || col + 1 == width,
+    );
+    if flooded.goal().is_some() {
+        return None;
+    }
+    Some(
+        in_loop
+            .positions()
+            .map(Position::from)
+            .filter(|&position| {
+                position.row % 2 == 0 && position.col % 2 == 0 && flooded.contains(&position)
+            })
+            .count(),
+    )
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(enclosed(parse_sketch(input)?))
+}
+
+/// How many tiles the loop encloses.
+fn enclosed(
+    Sketch {
+        grid,
+        start,
+        pipe_loop,
+    }: Sketch,
+) -> usize {
+    // Tile (row, col) becomes (2 * row, 2 * col) of a grid twice the size,
+    // with the pipe between two neighbouring loop tiles filled in, so the
+    // flood can squeeze between pipes that aren't connected.
+    let mut in_loop = Grid::new(grid.width() * 2 - 1, grid.height() * 2 - 1, false);
+    for (prev, next) in pipe_loop.into_iter().circular_tuple_windows() {
+        in_loop[Position::new(prev.row * 2, prev.col * 2)] = true;
+        in_loop[Position::new(prev.row + next.row, prev.col + next.col)] = true;
+    }
+    let start = Position::new(start.row * 2, start.col * 2);
+
+    // One of the start's diagonal neighbours is always inside the loop.
+    [Direction::Down, Direction::Up]
+        .into_iter()
+        .flat_map(|vertical| [(vertical, Direction::Left), (vertical, Direction::Right)])
+        .filter_map(|(vertical, horizontal)| {
+            in_loop.step(in_loop.step(start, vertical)?, horizontal)
+        })
+        .filter(|&try_in_loop| !in_loop[try_in_loop])
+        .find_map(|try_in_loop| flood(&in_loop, try_in_loop))
+        .expect("no tile next to the start is inside the loop")
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Sketch;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Sketch, Error> {
+        parse_sketch(input)
+    }
+
+    fn solve(sketch: Sketch, _: &Params) -> Result<usize, Error> {
+        Ok(enclosed(sketch))
+    }
+}
+
+const EXAMPLE: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(4));
+
+        let result = part2(
+            ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...",
+        );
+        assert_eq!(result, Ok(8))
+    }
+}
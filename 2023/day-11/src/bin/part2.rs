@@ -1,81 +1,9 @@
-use itertools::Itertools;
+use aoc_common::{Params, Solution};
+use day_2023_11::part2::Part2;
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let output = part2(input);
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = aoc_common::input!()?;
+    let output = Part2::run(&input, &Params::defaults(Part2::PARAMS))?;
     dbg!(output);
-}
-
-#[derive(Debug, Clone, Copy)]
-struct Position {
-    row: usize,
-    col: usize,
-}
-
-fn part2(input: &str) -> usize {
-    let input_rows: Vec<&str> = input.lines().collect();
-    let empty_cols: Vec<usize> = (0..input_rows[0].len())
-        .filter(|&j| (0..input_rows.len()).all(|i| input_rows[i].chars().nth(j).unwrap() != '#'))
-        .collect();
-    let mut empty_row_count = 0;
-    input_rows
-        .iter()
-        .enumerate()
-        .filter_map(|(i, &row)| {
-            let mut row_galaxies = row
-                .chars()
-                .enumerate()
-                .filter_map(|(j, c)| {
-                    if c == '#' {
-                        Some(Position {
-                            row: i + empty_row_count * 999999,
-                            col: j + empty_cols
-                                .iter()
-                                .take_while(|&&empty_col| empty_col <= j)
-                                .count()
-                                * 999999,
-                        })
-                    } else {
-                        None
-                    }
-                })
-                .peekable();
-            if row_galaxies.peek().is_some() {
-                Some(row_galaxies.collect::<Vec<Position>>())
-            } else {
-                empty_row_count += 1;
-                None
-            }
-        })
-        .flatten()
-        .combinations(2)
-        .map(|combination| {
-            let g1 = combination[0];
-            let g2 = combination[1];
-            ((g1.row as isize - g2.row as isize).abs() + (g1.col as isize - g2.col as isize).abs())
-                as usize
-        })
-        .sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part2_test() {
-        let result = part2(
-            "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....",
-        );
-        assert_eq!(result, 1030);
-    }
+    Ok(())
 }
@@ -0,0 +1,46 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    params::ParamError,
+    parse::{self, ParseError},
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A parameter's value isn't one the solution can use.
+    Param(ParamError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Param(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Param(err)
+    }
+}
+
+/// The image's rows, checked to be a rectangle of `#` and `.` so every row
+/// has a tile in every column.
+fn parse_image(input: &str) -> Result<Vec<&str>, Error> {
+    let tile = |c| matches!(c, '#' | '.').then_some(());
+    parse::parse(input, parse::grid("'#' or '.'", tile))?;
+    Ok(input.lines().collect())
+}
@@ -0,0 +1,102 @@
+use crate::{parse_image, Error};
+use aoc_common::{Params, Solution};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    row: usize,
+    col: usize,
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(distance_sum(&parse_image(input)?))
+}
+
+/// The sum of the distances between every pair of galaxies.
+fn distance_sum(input_rows: &[&str]) -> usize {
+    let empty_cols: Vec<usize> = (0..input_rows[0].len())
+        .filter(|&j| (0..input_rows.len()).all(|i| input_rows[i].chars().nth(j).unwrap() != '#'))
+        .collect();
+    let mut empty_row_count = 0;
+    input_rows
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &row)| {
+            let mut row_galaxies = row
+                .chars()
+                .enumerate()
+                .filter_map(|(j, c)| {
+                    if c == '#' {
+                        Some(Position {
+                            row: i + empty_row_count,
+                            col: j + empty_cols
+                                .iter()
+                                .take_while(|&&empty_col| empty_col <= j)
+                                .count(),
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .peekable();
+            if row_galaxies.peek().is_some() {
+                Some(row_galaxies.collect::<Vec<Position>>())
+            } else {
+                empty_row_count += 1;
+                None
+            }
+        })
+        .flatten()
+        .combinations(2)
+        .map(|combination| {
+            let g1 = combination[0];
+            let g2 = combination[1];
+            ((g1.row as isize - g2.row as isize).abs() + (g1.col as isize - g2.col as isize).abs())
+                as usize
+        })
+        .sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<&'a str>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Error> {
+        parse_image(input)
+    }
+
+    fn solve(image: Vec<&str>, _: &Params) -> Result<usize, Error> {
+        Ok(distance_sum(&image))
+    }
+}
+
+const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(374));
+    }
+}
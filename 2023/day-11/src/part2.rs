@@ -0,0 +1,120 @@
+use crate::{parse_image, Error};
+use aoc_common::{params::ParamError, Param, Params, Solution};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    row: usize,
+    col: usize,
+}
+
+pub fn part2(input: &str, expansion: usize) -> Result<usize, Error> {
+    distance_sum(&parse_image(input)?, expansion)
+}
+
+/// The sum of the distances between every pair of galaxies, once each
+/// empty row and column is `expansion` of them.
+fn distance_sum(input_rows: &[&str], expansion: usize) -> Result<usize, Error> {
+    if expansion == 0 {
+        return Err(Error::Param(ParamError::Invalid {
+            name: "expansion".to_owned(),
+            value: expansion.to_string(),
+            reason: "an empty row or column can't disappear".to_owned(),
+        }));
+    }
+    let empty_cols: Vec<usize> = (0..input_rows[0].len())
+        .filter(|&j| (0..input_rows.len()).all(|i| input_rows[i].chars().nth(j).unwrap() != '#'))
+        .collect();
+    let mut empty_row_count = 0;
+    Ok(input_rows
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &row)| {
+            let mut row_galaxies = row
+                .chars()
+                .enumerate()
+                .filter_map(|(j, c)| {
+                    if c == '#' {
+                        Some(Position {
+                            row: i + empty_row_count * (expansion - 1),
+                            col: j + empty_cols
+                                .iter()
+                                .take_while(|&&empty_col| empty_col <= j)
+                                .count()
+                                * (expansion - 1),
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .peekable();
+            if row_galaxies.peek().is_some() {
+                Some(row_galaxies.collect::<Vec<Position>>())
+            } else {
+                empty_row_count += 1;
+                None
+            }
+        })
+        .flatten()
+        .combinations(2)
+        .map(|combination| {
+            let g1 = combination[0];
+            let g2 = combination[1];
+            ((g1.row as isize - g2.row as isize).abs() + (g1.col as isize - g2.col as isize).abs())
+                as usize
+        })
+        .sum())
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const PARAMS: &'static [Param] = &[Param {
+        name: "expansion",
+        default: "1000000",
+        about: "how many rows or columns each empty one becomes",
+    }];
+
+    type Input<'a> = Vec<&'a str>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Error> {
+        parse_image(input)
+    }
+
+    fn solve(image: Vec<&str>, params: &Params) -> Result<usize, Error> {
+        distance_sum(&image, params.get("expansion")?)
+    }
+}
+
+const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE, 10);
+        assert_eq!(result, Ok(1030));
+        assert!(matches!(
+            part2(EXAMPLE, 0),
+            Err(Error::Param(ParamError::Invalid { name, .. })) if name == "expansion"
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::Error;
+use aoc_common::{
+    parse::{
+        lines, pair, parse, separated, spaces, tag, take_while1, terminated, try_map, unsigned,
+        ParseResult,
+    },
+    Params, Solution,
+};
+
+fn total_positions(space: &str, groups: &[usize]) -> usize {
+    let group = groups[groups.len() - 1];
+    let groups = &groups[0..groups.len() - 1];
+    space
+        .chars()
+        .take_while(|&c| c != '#')
+        .chain(space.chars().skip_while(|&c| c != '#').take(group))
+        .collect::<Vec<char>>()
+        .windows(group)
+        .enumerate()
+        .filter_map(|(i, window)| {
+            if window.contains(&'.') {
+                None
+            } else if i + group == space.len() {
+                if groups.is_empty() {
+                    Some(1)
+                } else {
+                    None
+                }
+            } else if (i >= 1 && space.chars().nth(i - 1).unwrap() == '#')
+                || space.chars().nth(i + group).unwrap() == '#'
+            {
+                None
+            } else {
+                let try_new_space = &space[i + group + 1..];
+                if groups.is_empty() {
+                    if try_new_space.chars().all(|c| c != '#') {
+                        Some(1)
+                    } else {
+                        None
+                    }
+                } else {
+                    Some(total_positions(try_new_space, groups))
+                }
+            }
+        })
+        .sum()
+}
+
+/// A row's springs and the sizes of its groups of damaged ones.
+pub type Record<'a> = (&'a str, Vec<usize>);
+
+fn parse_records(input: &str) -> ParseResult<Vec<Record<'_>>> {
+    let springs = take_while1("'.', '#' or '?'", |c| matches!(c, '.' | '#' | '?'));
+    let size = try_map(unsigned::<usize>(), |size| {
+        if size > 0 {
+            Ok(size)
+        } else {
+            Err("a group size above 0".to_string())
+        }
+    });
+    let record = pair(terminated(springs, spaces()), separated(size, tag(",")));
+    parse(input, lines(record))
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(arrangements(parse_records(input)?))
+}
+
+/// The sum of every row's possible arrangements.
+fn arrangements(records: Vec<Record<'_>>) -> usize {
+    records
+        .into_iter()
+        .map(|(space, groups)| {
+            let groups: Vec<usize> = groups.into_iter().rev().collect();
+            total_positions(space, &groups)
+        })
+        .sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Record<'a>>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Record<'_>>, Error> {
+        Ok(parse_records(input)?)
+    }
+
+    fn solve(records: Vec<Record<'_>>, _: &Params) -> Result<usize, Error> {
+        Ok(arrangements(records))
+    }
+}
+
+const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(21));
+    }
+}
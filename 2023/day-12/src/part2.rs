@@ -0,0 +1,135 @@
+use crate::Error;
+use aoc_common::{
+    parse::{
+        lines, pair, parse, separated, spaces, tag, take_while1, terminated, try_map, unsigned,
+        ParseResult,
+    },
+    Params, Solution,
+};
+use itertools::Itertools;
+use memoize::memoize;
+use rayon::prelude::*;
+
+#[memoize]
+fn total_positions(space: String, groups: Vec<usize>) -> usize {
+    let group = groups[groups.len() - 1];
+    let groups = &groups[0..groups.len() - 1];
+    // The groups after this one, with a gap before each, have to fit after
+    // it.
+    let Some(mut remaining_upper_bound) = space
+        .len()
+        .checked_sub(groups.iter().sum::<usize>() + groups.len())
+    else {
+        return 0;
+    };
+    if let Some(first_operational_block) = space.find('#') {
+        remaining_upper_bound = remaining_upper_bound.min(first_operational_block + group);
+    }
+    space[..remaining_upper_bound]
+        .chars()
+        .collect::<Vec<char>>()
+        .windows(group)
+        .enumerate()
+        .filter_map(|(i, window)| {
+            if window.contains(&'.') {
+                None
+            } else if i + group == space.len() {
+                if groups.is_empty() {
+                    Some(1)
+                } else {
+                    None
+                }
+            } else if (i >= 1 && space.chars().nth(i - 1).unwrap() == '#')
+                || space.chars().nth(i + group).unwrap() == '#'
+            {
+                None
+            } else {
+                let try_new_space = &space[i + group + 1..];
+                if groups.is_empty() {
+                    if try_new_space.chars().all(|c| c != '#') {
+                        Some(1)
+                    } else {
+                        None
+                    }
+                } else {
+                    Some(total_positions(try_new_space.to_owned(), groups.to_vec()))
+                }
+            }
+        })
+        .sum()
+}
+
+/// A row's springs and the sizes of its groups of damaged ones.
+pub type Record<'a> = (&'a str, Vec<usize>);
+
+fn parse_records(input: &str) -> ParseResult<Vec<Record<'_>>> {
+    let springs = take_while1("'.', '#' or '?'", |c| matches!(c, '.' | '#' | '?'));
+    let size = try_map(unsigned::<usize>(), |size| {
+        if size > 0 {
+            Ok(size)
+        } else {
+            Err("a group size above 0".to_string())
+        }
+    });
+    let record = pair(terminated(springs, spaces()), separated(size, tag(",")));
+    parse(input, lines(record))
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(arrangements(parse_records(input)?))
+}
+
+/// The sum of every row's possible arrangements.
+fn arrangements(records: Vec<Record<'_>>) -> usize {
+    records
+        .into_par_iter()
+        .map(|(space, groups)| {
+            let space =
+                Itertools::intersperse(std::iter::repeat_n(space, 5), "?").collect::<String>();
+            let groups = std::iter::repeat_n(groups.into_iter().rev(), 5)
+                .flatten()
+                .collect::<Vec<usize>>();
+            total_positions(space, groups)
+        })
+        .sum()
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Record<'a>>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Record<'_>>, Error> {
+        Ok(parse_records(input)?)
+    }
+
+    fn solve(records: Vec<Record<'_>>, _: &Params) -> Result<usize, Error> {
+        Ok(arrangements(records))
+    }
+}
+
+const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(525152));
+    }
+}
@@ -0,0 +1,40 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    parse::{grid, parse, sections, ParseError},
+    Grid,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A pattern, counted from 1, with no line of reflection.
+    NoReflection {
+        pattern: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::NoReflection { pattern } => write!(f, "pattern {pattern} has no reflection"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Every pattern of ash and rocks.
+fn parse_patterns(input: &str) -> Result<Vec<Grid<char>>, Error> {
+    let tile = |c| matches!(c, '#' | '.').then_some(c);
+    Ok(parse(input, sections(grid("'#' or '.'", tile)))?)
+}
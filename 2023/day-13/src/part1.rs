@@ -0,0 +1,97 @@
+use crate::{parse_patterns, Error};
+use aoc_common::{Grid, Params, Solution};
+use itertools::Itertools;
+
+fn find_reflection_index(pattern: &Grid<char>) -> Option<usize> {
+    let pattern: Vec<&[char]> = pattern.rows().collect();
+    pattern
+        .iter()
+        .tuple_windows()
+        .enumerate()
+        .find_map(|(i, (curr, next))| {
+            if curr != next {
+                return None;
+            }
+            let mut check_line_index = 0;
+            loop {
+                let before = i as isize - check_line_index as isize;
+                let after = i + check_line_index + 1;
+                if before == -1 || after == pattern.len() {
+                    break Some(i + 1);
+                } else if pattern[before as usize] != pattern[after] {
+                    break None;
+                }
+                check_line_index += 1;
+            }
+        })
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    summarize(&parse_patterns(input)?)
+}
+
+/// The columns left of each vertical line of reflection, plus 100 times
+/// the rows above each horizontal one.
+fn summarize(patterns: &[Grid<char>]) -> Result<usize, Error> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(index, pattern)| {
+            if let Some(horizontal_reflection_index) = find_reflection_index(pattern) {
+                Ok(horizontal_reflection_index * 100)
+            } else {
+                find_reflection_index(&pattern.transpose())
+                    .ok_or(Error::NoReflection { pattern: index + 1 })
+            }
+        })
+        .sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Grid<char>>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Grid<char>>, Error> {
+        parse_patterns(input)
+    }
+
+    fn solve(patterns: Vec<Grid<char>>, _: &Params) -> Result<usize, Error> {
+        summarize(&patterns)
+    }
+}
+
+const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(405));
+    }
+}
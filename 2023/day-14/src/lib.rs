@@ -0,0 +1,35 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    parse::{grid, parse, ParseError},
+    Grid,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// The platform's round rocks, cube rocks and empty spaces.
+fn parse_platform(input: &str) -> Result<Grid<char>, Error> {
+    let tile = |c| matches!(c, 'O' | '#' | '.').then_some(c);
+    Ok(parse(input, grid("'O', '#' or '.'", tile))?)
+}
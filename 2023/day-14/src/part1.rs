@@ -0,0 +1,71 @@
+use crate::{parse_platform, Error};
+use aoc_common::{Grid, Params, Solution};
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(north_load(parse_platform(input)?))
+}
+
+/// The load on the north beams once every round rock rolls north.
+fn north_load(mut grid: Grid<char>) -> usize {
+    for j in 0..grid.width() {
+        for i in 0..grid.height() {
+            if grid[(i, j)] != 'O' {
+                continue;
+            }
+            let mut k = i;
+            while k >= 1 && grid[(k - 1, j)] == '.' {
+                grid.swap((k - 1, j), (k, j));
+                k -= 1;
+            }
+        }
+    }
+    grid.rows()
+        .rev()
+        .enumerate()
+        .map(|(i, line)| (i + 1) * line.iter().filter(|&&c| c == 'O').count())
+        .sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Grid<char>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Grid<char>, Error> {
+        parse_platform(input)
+    }
+
+    fn solve(grid: Grid<char>, _: &Params) -> Result<usize, Error> {
+        Ok(north_load(grid))
+    }
+}
+
+const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(136));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{parse_platform, Error};
+use aoc_common::{cycle, Grid, Params, Solution};
+
+/// Rolls every round rock as far north as it goes.
+fn tilt_north(grid: &mut Grid<char>) {
+    for j in 0..grid.width() {
+        for i in 0..grid.height() {
+            if grid[(i, j)] != 'O' {
+                continue;
+            }
+            let mut k = i;
+            while k >= 1 && grid[(k - 1, j)] == '.' {
+                grid.swap((k - 1, j), (k, j));
+                k -= 1;
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east in turn, by tilting north and turning
+/// the grid clockwise so the next side to tilt to is at the top.
+fn spin_cycle(grid: &mut Grid<char>) {
+    for _ in 0..4 {
+        tilt_north(grid);
+        *grid = grid.rotate_clockwise();
+    }
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(north_load(parse_platform(input)?))
+}
+
+/// The load on the north beams after a billion spin cycles.
+fn north_load(grid: Grid<char>) -> usize {
+    let spun = |grid: &Grid<char>| {
+        let mut grid = grid.clone();
+        spin_cycle(&mut grid);
+        grid
+    };
+    let final_grid = cycle::find_cycle(grid.clone(), spun).nth_state(grid, spun, 1_000_000_000);
+    final_grid
+        .rows()
+        .rev()
+        .enumerate()
+        .map(|(i, line)| (i + 1) * line.iter().filter(|&&c| c == 'O').count())
+        .sum()
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Grid<char>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Grid<char>, Error> {
+        parse_platform(input)
+    }
+
+    fn solve(grid: Grid<char>, _: &Params) -> Result<usize, Error> {
+        Ok(north_load(grid))
+    }
+}
+
+const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(64));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::Error;
+use aoc_common::{Params, Solution};
+
+fn hash(to_hash: &str) -> usize {
+    to_hash.chars().fold(0, |current_value, c| {
+        if c == '\n' {
+            return current_value;
+        }
+        let mut next_value = current_value + c as usize;
+        next_value *= 17;
+        next_value %= 256;
+        next_value
+    })
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(hash_sum(&parse_steps(input)))
+}
+
+/// The comma-separated steps of the initialization sequence.
+fn parse_steps(input: &str) -> Vec<&str> {
+    input.split(',').collect()
+}
+
+fn hash_sum(steps: &[&str]) -> usize {
+    steps.iter().copied().map(hash).sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<&'a str>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Error> {
+        Ok(parse_steps(input))
+    }
+
+    fn solve(steps: Vec<&str>, _: &Params) -> Result<usize, Error> {
+        Ok(hash_sum(&steps))
+    }
+}
+
+const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(1320));
+    }
+}
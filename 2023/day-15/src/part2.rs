@@ -0,0 +1,105 @@
+use crate::Error;
+use aoc_common::{
+    parse::{alt, map, pair, parse, preceded, separated, tag, take_while1, unsigned},
+    Params, Solution,
+};
+
+fn hash(to_hash: &str) -> usize {
+    to_hash.chars().fold(0, |current_value, c| {
+        if c == '\n' {
+            return current_value;
+        }
+        let mut next_value = current_value + c as usize;
+        next_value *= 17;
+        next_value %= 256;
+        next_value
+    })
+}
+
+#[derive(Debug)]
+struct Lens(String, usize);
+
+/// A step's label, and the focal length to put in or `None` to take the
+/// lens out.
+pub type Step<'a> = (&'a str, Option<usize>);
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(focusing_power(parse_steps(input)?))
+}
+
+fn parse_steps(input: &str) -> Result<Vec<Step<'_>>, Error> {
+    let step = pair(
+        take_while1("a label", char::is_alphabetic),
+        alt(
+            map(tag("-"), |_| None),
+            map(preceded(tag("="), unsigned::<usize>()), Some),
+        ),
+    );
+    Ok(parse(input, separated(step, tag(",")))?)
+}
+
+/// The focusing power of every lens once the steps are done.
+fn focusing_power(steps: Vec<Step<'_>>) -> usize {
+    let mut boxes: [Vec<Lens>; 256] = std::array::from_fn(|_| Vec::new());
+    for (lens_label, focal_length) in steps {
+        let box_ = &mut boxes[hash(lens_label)];
+        match focal_length {
+            None => {
+                box_.retain(|lens| lens.0 != lens_label);
+            }
+            Some(focal_length) => match box_.iter().position(|lens| lens.0 == lens_label) {
+                Some(same_label_index) => {
+                    box_[same_label_index].1 = focal_length;
+                }
+                None => {
+                    let lens = Lens(lens_label.to_string(), focal_length);
+                    box_.push(lens);
+                }
+            },
+        };
+    }
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, box_)| {
+            box_.iter()
+                .enumerate()
+                .map(move |(j, lens)| (i + 1) * (j + 1) * lens.1)
+        })
+        .sum()
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Step<'a>>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Step<'_>>, Error> {
+        parse_steps(input)
+    }
+
+    fn solve(steps: Vec<Step<'_>>, _: &Params) -> Result<usize, Error> {
+        Ok(focusing_power(steps))
+    }
+}
+
+const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(145));
+    }
+}
@@ -0,0 +1,64 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    parse::{self, ParseError},
+    Grid,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// A tile of the contraption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    /// `.`
+    Empty,
+    /// `/`
+    Mirror,
+    /// `\`
+    BackMirror,
+    /// `-`
+    HorizontalSplitter,
+    /// `|`
+    VerticalSplitter,
+}
+
+impl Tile {
+    fn from(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::Mirror),
+            '\\' => Some(Tile::BackMirror),
+            '-' => Some(Tile::HorizontalSplitter),
+            '|' => Some(Tile::VerticalSplitter),
+            _ => None,
+        }
+    }
+}
+
+fn parse_contraption(input: &str) -> Result<Grid<Tile>, Error> {
+    Ok(parse::parse(
+        input,
+        parse::grid(r"'.', '/', '\', '-' or '|'", Tile::from),
+    )?)
+}
@@ -0,0 +1,107 @@
+use crate::{parse_contraption, Error, Tile};
+use aoc_common::{
+    search::{self, Dense},
+    Direction, Grid, Params, Position, Solution,
+};
+
+fn reflect_direction(direction: Direction, reflector: Tile) -> Vec<Direction> {
+    match (reflector, direction.is_vertical()) {
+        (Tile::Mirror, true) | (Tile::BackMirror, false) => vec![direction.turn_right()],
+        (Tile::Mirror, false) | (Tile::BackMirror, true) => vec![direction.turn_left()],
+        (Tile::HorizontalSplitter, true) | (Tile::VerticalSplitter, false) => {
+            direction.turns().to_vec()
+        }
+        (Tile::HorizontalSplitter, false) | (Tile::VerticalSplitter, true) | (Tile::Empty, _) => {
+            vec![direction]
+        }
+    }
+}
+
+/// How many tiles a beam entering `start` going `direction` energizes. The
+/// search is over where a beam is and which way it's going, so it stops once
+/// the beams start going round in circles.
+fn energized(grid: &Grid<Tile>, start: Position, direction: Direction) -> usize {
+    let width = grid.width();
+    let beams = search::bfs_in(
+        Dense::new(
+            grid.width() * grid.height() * 4,
+            |&(position, direction): &(Position, Direction)| {
+                (position.row * width + position.col) * 4 + direction as usize
+            },
+        ),
+        (start, direction),
+        |&(position, direction)| {
+            reflect_direction(direction, grid[position])
+                .into_iter()
+                .filter_map(move |direction| Some((grid.step(position, direction)?, direction)))
+        },
+        |_| false,
+    );
+    grid.positions()
+        .filter(|&position| {
+            Direction::ALL
+                .into_iter()
+                .any(|direction| beams.contains(&(position.into(), direction)))
+        })
+        .count()
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(energized(
+        &parse_contraption(input)?,
+        Position::new(0, 0),
+        Direction::Right,
+    ))
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Grid<Tile>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Grid<Tile>, Error> {
+        parse_contraption(input)
+    }
+
+    fn solve(grid: Grid<Tile>, _: &Params) -> Result<usize, Error> {
+        Ok(energized(&grid, Position::new(0, 0), Direction::Right))
+    }
+}
+
+const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::parse::ParseError;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(46));
+        assert_eq!(
+            part1(".|.\n.x."),
+            Err(Error::Parse(ParseError {
+                line: 2,
+                column: 2,
+                expected: r"'.', '/', '\', '-' or '|'".to_owned()
+            }))
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{parse_contraption, Error, Tile};
+use aoc_common::{
+    search::{self, Dense},
+    Direction, Grid, Params, Position, Solution,
+};
+
+fn reflect_direction(direction: Direction, reflector: Tile) -> Vec<Direction> {
+    match (reflector, direction.is_vertical()) {
+        (Tile::Mirror, true) | (Tile::BackMirror, false) => vec![direction.turn_right()],
+        (Tile::Mirror, false) | (Tile::BackMirror, true) => vec![direction.turn_left()],
+        (Tile::HorizontalSplitter, true) | (Tile::VerticalSplitter, false) => {
+            direction.turns().to_vec()
+        }
+        (Tile::HorizontalSplitter, false) | (Tile::VerticalSplitter, true) | (Tile::Empty, _) => {
+            vec![direction]
+        }
+    }
+}
+
+/// How many tiles a beam entering `start` going `direction` energizes. The
+/// search is over where a beam is and which way it's going, so it stops once
+/// the beams start going round in circles.
+fn energized(grid: &Grid<Tile>, start: Position, direction: Direction) -> usize {
+    let width = grid.width();
+    let beams = search::bfs_in(
+        Dense::new(
+            grid.width() * grid.height() * 4,
+            |&(position, direction): &(Position, Direction)| {
+                (position.row * width + position.col) * 4 + direction as usize
+            },
+        ),
+        (start, direction),
+        |&(position, direction)| {
+            reflect_direction(direction, grid[position])
+                .into_iter()
+                .filter_map(move |direction| Some((grid.step(position, direction)?, direction)))
+        },
+        |_| false,
+    );
+    grid.positions()
+        .filter(|&position| {
+            Direction::ALL
+                .into_iter()
+                .any(|direction| beams.contains(&(position.into(), direction)))
+        })
+        .count()
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(most_energized(&parse_contraption(input)?))
+}
+
+/// The most tiles a beam entering from any edge tile energizes.
+fn most_energized(grid: &Grid<Tile>) -> usize {
+    let row_count = grid.height();
+    let col_count = grid.width();
+    (0..col_count)
+        .map(|j| energized(grid, Position::new(0, j), Direction::Down))
+        .chain(
+            (0..row_count)
+                .map(|i| energized(grid, Position::new(i, col_count - 1), Direction::Left)),
+        )
+        .chain(
+            (0..col_count).map(|j| energized(grid, Position::new(row_count - 1, j), Direction::Up)),
+        )
+        .chain((0..row_count).map(|i| energized(grid, Position::new(i, 0), Direction::Right)))
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Grid<Tile>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Grid<Tile>, Error> {
+        parse_contraption(input)
+    }
+
+    fn solve(grid: Grid<Tile>, _: &Params) -> Result<usize, Error> {
+        Ok(most_energized(&grid))
+    }
+}
+
+const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(51));
+    }
+}
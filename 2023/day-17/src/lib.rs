@@ -0,0 +1,40 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    parse::{grid, parse, ParseError},
+    Grid,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// The crucible can't reach the factory at all.
+    NoPath,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::NoPath => write!(f, "no path to the factory"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// The heat each city block loses the crucible.
+fn parse_map(input: &str) -> Result<Grid<usize>, Error> {
+    Ok(parse(
+        input,
+        grid("a digit", |c| c.to_digit(10).map(|digit| digit as usize)),
+    )?)
+}
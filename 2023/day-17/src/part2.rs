@@ -0,0 +1,91 @@
+use crate::{parse_map, Error};
+use aoc_common::{search, Direction, Grid, Params, Position, Solution};
+
+/// Where the crucible is and the direction and number of blocks it has
+/// been going in a straight line, if it has moved yet.
+type State = (Position, Option<(Direction, usize)>);
+
+fn successors(&(position, from): &State, grid: &Grid<usize>) -> Vec<(State, usize)> {
+    let directions: Vec<Direction> = match from {
+        Some((direction, streak)) => direction
+            .turns()
+            .into_iter()
+            .filter(|_| streak >= 4)
+            .chain((streak < 10).then_some(direction))
+            .collect(),
+        None => Direction::ALL.to_vec(),
+    };
+    directions
+        .into_iter()
+        .filter_map(|direction| {
+            let neighbor = grid.step(position, direction)?;
+            let streak = match from {
+                Some((from_direction, streak)) if from_direction == direction => streak + 1,
+                _ => 1,
+            };
+            Some(((neighbor, Some((direction, streak))), grid[neighbor]))
+        })
+        .collect()
+}
+
+/// The heat lost on the best way to the factory, if there is one.
+fn least_heat_loss(grid: &Grid<usize>) -> Option<usize> {
+    let goal = Position::new(grid.height() - 1, grid.width() - 1);
+    let search = search::dijkstra(
+        (Position::new(0, 0), None),
+        |state| successors(state, grid),
+        |&(position, _)| position == goal,
+    );
+    search.path().map(|path| path.cost)
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    least_heat_loss(&parse_map(input)?).ok_or(Error::NoPath)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Grid<usize>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Grid<usize>, Error> {
+        parse_map(input)
+    }
+
+    fn solve(grid: Grid<usize>, _: &Params) -> Result<usize, Error> {
+        least_heat_loss(&grid).ok_or(Error::NoPath)
+    }
+}
+
+const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(94));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::Error;
+use aoc_common::{
+    parse::{
+        delimited, lines, map, pair, parse, spaces, tag, take_while1, terminated, try_map, unsigned,
+    },
+    Direction, Params, Point, Solution,
+};
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(lagoon_size(&parse_plan(input)?))
+}
+
+/// Each step of the dig plan, as its direction and length, checked to end
+/// back where it starts.
+fn parse_plan(input: &str) -> Result<Vec<(Direction, usize)>, Error> {
+    let direction = try_map(take_while1("U, D, L or R", |c| c != ' '), |letter| {
+        let mut letters = letter.chars();
+        match (
+            letters.next().and_then(Direction::from_letter),
+            letters.next(),
+        ) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err("U, D, L or R".to_string()),
+        }
+    });
+    let colour = delimited(
+        tag("(#"),
+        take_while1("a colour", |c| c.is_ascii_hexdigit()),
+        tag(")"),
+    );
+    let step = pair(
+        terminated(direction, spaces()),
+        terminated(unsigned::<usize>(), pair(spaces(), map(colour, |_| ()))),
+    );
+    let plan = parse(input, lines(step))?;
+    let end = plan
+        .iter()
+        .fold(Point::default(), |point, &(direction, length)| {
+            point.step(direction, length as isize)
+        });
+    if end != Point::default() {
+        return Err(Error::NotClosed);
+    }
+    Ok(plan)
+}
+
+/// How many cubic metres of lava the dug-out lagoon holds.
+fn lagoon_size(plan: &[(Direction, usize)]) -> usize {
+    let mut current_point = Point::default();
+    let mut border_displacement = 0;
+    let mut boundary: Vec<Point> = Vec::new();
+    for &(line_direction, line_displacement) in plan {
+        border_displacement += line_displacement;
+        current_point = current_point.step(line_direction, line_displacement as isize);
+        boundary.push(current_point);
+    }
+    let area = (0..boundary.len())
+        .map(|i| {
+            let curr_y = boundary[i].row;
+            let prev_x = if i == 0 {
+                boundary[boundary.len() - 1]
+            } else {
+                boundary[i - 1]
+            }
+            .col;
+            let next_x = if i == boundary.len() - 1 {
+                boundary[0]
+            } else {
+                boundary[i + 1]
+            }
+            .col;
+            curr_y * (prev_x - next_x)
+        })
+        .sum::<isize>()
+        .unsigned_abs()
+        / 2;
+    // A = i + b/2 - 1
+    // i = A - b/2 + 1
+    let interior_points = area - border_displacement / 2 + 1;
+    interior_points + border_displacement
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<(Direction, usize)>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<(Direction, usize)>, Error> {
+        parse_plan(input)
+    }
+
+    fn solve(plan: Vec<(Direction, usize)>, _: &Params) -> Result<usize, Error> {
+        Ok(lagoon_size(&plan))
+    }
+}
+
+const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(62));
+        assert_eq!(Part1::parse("R 6 (#70c710)").err(), Some(Error::NotClosed));
+    }
+}
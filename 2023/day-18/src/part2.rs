@@ -0,0 +1,133 @@
+use crate::Error;
+use aoc_common::{
+    parse::{
+        delimited, lines, pair, parse, preceded, spaces, tag, take_while1, terminated, try_map,
+        unsigned,
+    },
+    Direction, Params, Point, Solution,
+};
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(lagoon_size(&parse_plan(input)?))
+}
+
+/// Each step of the dig plan, as its direction and length, checked to end
+/// back where it starts.
+fn parse_plan(input: &str) -> Result<Vec<(Direction, usize)>, Error> {
+    // The real step is hidden in the colour: five hex digits of distance
+    // and one of direction.
+    let hex_code = try_map(
+        take_while1("six hex digits", |c| c.is_ascii_hexdigit()),
+        |hex_code: &str| {
+            let direction = hex_code.chars().nth(5).and_then(Direction::from_hex_digit);
+            match (direction, hex_code.len()) {
+                (Some(direction), 6) => Ok((
+                    direction,
+                    usize::from_str_radix(&hex_code[..5], 16).expect("checked hex digits"),
+                )),
+                _ => Err("five hex digits then 0, 1, 2 or 3".to_string()),
+            }
+        },
+    );
+    let step = preceded(
+        pair(
+            terminated(take_while1("a direction", |c| c != ' '), spaces()),
+            terminated(unsigned::<usize>(), spaces()),
+        ),
+        delimited(tag("(#"), hex_code, tag(")")),
+    );
+    let plan = parse(input, lines(step))?;
+    let end = plan
+        .iter()
+        .fold(Point::default(), |point, &(direction, length)| {
+            point.step(direction, length as isize)
+        });
+    if end != Point::default() {
+        return Err(Error::NotClosed);
+    }
+    Ok(plan)
+}
+
+/// How many cubic metres of lava the dug-out lagoon holds.
+fn lagoon_size(plan: &[(Direction, usize)]) -> usize {
+    let mut current_point = Point::default();
+    let mut border_displacement = 0;
+    let mut boundary: Vec<Point> = Vec::new();
+    for &(line_direction, line_displacement) in plan {
+        border_displacement += line_displacement;
+        current_point = current_point.step(line_direction, line_displacement as isize);
+        boundary.push(current_point);
+    }
+    let area = (0..boundary.len())
+        .map(|i| {
+            let curr_y = boundary[i].row;
+            let prev_x = if i == 0 {
+                boundary[boundary.len() - 1]
+            } else {
+                boundary[i - 1]
+            }
+            .col;
+            let next_x = if i == boundary.len() - 1 {
+                boundary[0]
+            } else {
+                boundary[i + 1]
+            }
+            .col;
+            curr_y * (prev_x - next_x)
+        })
+        .sum::<isize>()
+        .unsigned_abs()
+        / 2;
+    // A = i + b/2 - 1
+    // i = A - b/2 + 1
+    let interior_points = area - border_displacement / 2 + 1;
+    interior_points + border_displacement
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<(Direction, usize)>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<(Direction, usize)>, Error> {
+        parse_plan(input)
+    }
+
+    fn solve(plan: Vec<(Direction, usize)>, _: &Params) -> Result<usize, Error> {
+        Ok(lagoon_size(&plan))
+    }
+}
+
+const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(952408144115));
+    }
+}
@@ -0,0 +1,142 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::parse::{
+    alt, delimited, lines, map, opt, pair, parse, preceded, separated, tag, take_while1,
+    terminated, try_map, unsigned, word, ParseError, Parser,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A rule sends parts to a workflow that isn't in the list.
+    UnknownWorkflow(String),
+    /// There's no `in` workflow for parts to start at.
+    NoStart,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::UnknownWorkflow(name) => write!(f, "no workflow is called {name}"),
+            Error::NoStart => write!(f, "no workflow is called in"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// A rule's test of one of a part's ratings.
+#[derive(Debug)]
+pub struct Condition {
+    operator: Operator,
+    operand1: PartRating,
+    operand2: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operator {
+    Less,
+    Greater,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PartRating {
+    X,
+    M,
+    A,
+    S,
+}
+
+/// A rule as written: its condition, unless it always applies, and the name
+/// of where it sends parts.
+pub type RawRule<'a> = (Option<Condition>, &'a str);
+
+/// A workflow's name and its rules in order.
+pub type RawWorkflow<'a> = (&'a str, Vec<RawRule<'a>>);
+
+/// The workflows, every rule but the last with a condition, and each part's
+/// x, m, a and s ratings. Every rule sends parts to `A`, `R` or a workflow
+/// in the list, and there's an `in` workflow to start at.
+pub struct System<'a> {
+    pub workflows: Vec<RawWorkflow<'a>>,
+    pub parts: Vec<[usize; 4]>,
+}
+
+fn condition<'a>() -> impl Parser<'a, Condition> {
+    let rating = try_map(
+        take_while1("x, m, a or s", char::is_alphabetic),
+        |name| match name {
+            "x" => Ok(PartRating::X),
+            "m" => Ok(PartRating::M),
+            "a" => Ok(PartRating::A),
+            "s" => Ok(PartRating::S),
+            _ => Err("x, m, a or s".to_string()),
+        },
+    );
+    let operator = alt(
+        map(tag("<"), |_| Operator::Less),
+        map(tag(">"), |_| Operator::Greater),
+    );
+    map(
+        pair(pair(rating, operator), unsigned::<usize>()),
+        |((operand1, operator), operand2)| Condition {
+            operator,
+            operand1,
+            operand2,
+        },
+    )
+}
+
+fn parse_system(input: &str) -> Result<System<'_>, Error> {
+    let conditional = map(
+        pair(terminated(condition(), tag(":")), word()),
+        |(condition, destination)| (Some(condition), destination),
+    );
+    let rules = map(
+        pair(
+            opt(terminated(separated(conditional, tag(",")), tag(","))),
+            word(),
+        ),
+        |(conditional, fallback)| {
+            let mut rules = conditional.unwrap_or_default();
+            rules.push((None, fallback));
+            rules
+        },
+    );
+    let workflow = pair(word(), delimited(tag("{"), rules, tag("}")));
+    let rating = |name| preceded(tag(name), unsigned::<usize>());
+    let part = delimited(
+        tag("{"),
+        pair(
+            pair(rating("x="), rating(",m=")),
+            pair(rating(",a="), rating(",s=")),
+        ),
+        tag("}"),
+    );
+    let part = map(part, |((x, m), (a, s))| [x, m, a, s]);
+    let (workflows, parts) = parse(
+        input,
+        pair(terminated(lines(workflow), tag("\n\n")), lines(part)),
+    )?;
+    let named = |name: &str| workflows.iter().any(|&(workflow, _)| workflow == name);
+    if let Some(&(_, destination)) = workflows
+        .iter()
+        .flat_map(|(_, rules)| rules)
+        .find(|&&(_, destination)| !matches!(destination, "A" | "R") && !named(destination))
+    {
+        return Err(Error::UnknownWorkflow(destination.to_string()));
+    }
+    if !named("in") {
+        return Err(Error::NoStart);
+    }
+    Ok(System { workflows, parts })
+}
@@ -0,0 +1,195 @@
+use crate::{parse_system, Condition, Error, Operator, PartRating, System};
+use aoc_common::{Params, Solution};
+use std::cell::RefCell;
+
+#[derive(Debug)]
+struct Workflow<'a> {
+    name: String,
+    rules: RefCell<Vec<Rule<'a>>>,
+}
+
+impl<'a> Workflow<'a> {
+    fn add_rule(&self, rule: Rule<'a>) {
+        self.rules.borrow_mut().push(rule);
+    }
+
+    fn to_dest(&self, part: &Part) -> Destination<'a> {
+        self.rules
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|rule| {
+                if rule.matches(part) {
+                    Some(rule.destination)
+                } else {
+                    None
+                }
+            })
+            .expect("the last rule always matches")
+    }
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    condition: Option<Condition>,
+    destination: Destination<'a>,
+}
+
+impl Rule<'_> {
+    fn matches(&self, part: &Part) -> bool {
+        match &self.condition {
+            None => true,
+            Some(condition) => {
+                let operand1 = match condition.operand1 {
+                    PartRating::X => part.x,
+                    PartRating::M => part.m,
+                    PartRating::A => part.a,
+                    PartRating::S => part.s,
+                };
+                match condition.operator {
+                    Operator::Greater => operand1 > condition.operand2,
+                    Operator::Less => operand1 < condition.operand2,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Destination<'a> {
+    R,
+    A,
+    Workflow(&'a Workflow<'a>),
+}
+
+impl Destination<'_> {
+    fn from<'a>(raw_from: &str, workflows: &'a [Workflow<'a>]) -> Destination<'a> {
+        match raw_from {
+            "A" => Destination::A,
+            "R" => Destination::R,
+            workflow_name => workflows
+                .iter()
+                .find(|workflow| workflow.name == workflow_name)
+                .map(Destination::Workflow)
+                .expect("parsing checks every workflow a rule names exists"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn rating_sum(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(rating_sum(parse_system(input)?))
+}
+
+/// The sum of the ratings of every part that ends up accepted.
+fn rating_sum(
+    System {
+        workflows: raw_workflows,
+        parts,
+    }: System<'_>,
+) -> usize {
+    let workflows: Vec<Workflow> = raw_workflows
+        .iter()
+        .map(|&(name, _)| Workflow {
+            name: name.to_string(),
+            rules: RefCell::from(Vec::new()),
+        })
+        .collect();
+    for (workflow, (_, raw_rules)) in workflows.iter().zip(raw_workflows) {
+        for (condition, raw_from) in raw_rules.into_iter().rev() {
+            workflow.add_rule(Rule {
+                condition,
+                destination: Destination::from(raw_from, &workflows),
+            });
+        }
+    }
+    let root_workflow = workflows
+        .iter()
+        .find(|workflow| workflow.name == "in")
+        .expect("parsing checks there's an in workflow");
+
+    parts
+        .into_iter()
+        .filter_map(|[x, m, a, s]| {
+            let part = Part { x, m, a, s };
+            let mut current_workflow = root_workflow;
+            loop {
+                match current_workflow.to_dest(&part) {
+                    Destination::Workflow(dest_workflow) => {
+                        current_workflow = dest_workflow;
+                    }
+                    Destination::A => break Some(part.rating_sum()),
+                    Destination::R => break None,
+                }
+            }
+        })
+        .sum()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = System<'a>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<System<'_>, Error> {
+        parse_system(input)
+    }
+
+    fn solve(system: System<'_>, _: &Params) -> Result<usize, Error> {
+        Ok(rating_sum(system))
+    }
+}
+
+const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(19114));
+        assert_eq!(
+            part1("in{x<5:A,nope}\n\n{x=1,m=2,a=3,s=4}"),
+            Err(Error::UnknownWorkflow("nope".to_string()))
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{parse_system, Condition, Error, Operator, System};
+use aoc_common::{
+    interval::{Hyperrectangle, Interval},
+    Params, Solution,
+};
+use std::cell::RefCell;
+
+#[derive(Debug)]
+struct Workflow<'a> {
+    name: String,
+    rules: RefCell<Vec<Rule<'a>>>,
+}
+
+impl<'a> Workflow<'a> {
+    fn add_rule(&self, rule: Rule<'a>) {
+        self.rules.borrow_mut().push(rule);
+    }
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    condition: Option<Condition>,
+    destination: Destination<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Destination<'a> {
+    R,
+    A,
+    Workflow(&'a Workflow<'a>),
+}
+
+impl Destination<'_> {
+    fn from<'a>(raw_from: &str, workflows: &'a [Workflow<'a>]) -> Destination<'a> {
+        match raw_from {
+            "A" => Destination::A,
+            "R" => Destination::R,
+            workflow_name => workflows
+                .iter()
+                .find(|workflow| workflow.name == workflow_name)
+                .map(Destination::Workflow)
+                .expect("parsing checks every workflow a rule names exists"),
+        }
+    }
+}
+
+/// Every combination of ratings still in play, one axis per `PartRating`.
+type PartRange = Hyperrectangle<4>;
+
+impl Condition {
+    /// The parts of `part_range` that meet the condition and the parts that
+    /// don't.
+    fn split(&self, part_range: PartRange) -> (Option<PartRange>, Option<PartRange>) {
+        let axis = self.operand1 as usize;
+        let compare_to = self.operand2 as i64;
+        match self.operator {
+            Operator::Greater => {
+                let (fails, passes) = part_range.split_at(axis, compare_to + 1);
+                (passes, fails)
+            }
+            Operator::Less => part_range.split_at(axis, compare_to),
+        }
+    }
+}
+
+fn accepted_count(mut part_range: PartRange, workflow: &Workflow) -> usize {
+    let mut ret = 0;
+    for rule in workflow.rules.borrow().iter().rev() {
+        let (matching, rest) = match &rule.condition {
+            Some(condition) => condition.split(part_range),
+            None => (Some(part_range), None),
+        };
+        if let Some(matching) = matching {
+            ret += match rule.destination {
+                Destination::A => matching.volume() as usize,
+                Destination::R => 0,
+                Destination::Workflow(destination_workflow) => {
+                    accepted_count(matching, destination_workflow)
+                }
+            }
+        }
+        let Some(rest) = rest else {
+            break;
+        };
+        part_range = rest;
+    }
+    ret
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Ok(combinations(parse_system(input)?))
+}
+
+/// How many combinations of ratings from 1 to 4000 the workflows accept.
+fn combinations(
+    System {
+        workflows: raw_workflows,
+        ..
+    }: System<'_>,
+) -> usize {
+    let workflows: Vec<Workflow> = raw_workflows
+        .iter()
+        .map(|&(name, _)| Workflow {
+            name: name.to_string(),
+            rules: RefCell::from(Vec::new()),
+        })
+        .collect();
+    for (workflow, (_, raw_rules)) in workflows.iter().zip(raw_workflows) {
+        for (condition, raw_from) in raw_rules.into_iter().rev() {
+            workflow.add_rule(Rule {
+                condition,
+                destination: Destination::from(raw_from, &workflows),
+            });
+        }
+    }
+    let root_workflow = workflows
+        .iter()
+        .find(|workflow| workflow.name == "in")
+        .expect("parsing checks there's an in workflow");
+    accepted_count(
+        Hyperrectangle([Interval::inclusive(1, 4000); 4]),
+        root_workflow,
+    )
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = System<'a>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<System<'_>, Error> {
+        parse_system(input)
+    }
+
+    fn solve(system: System<'_>, _: &Params) -> Result<usize, Error> {
+        Ok(combinations(system))
+    }
+}
+
+const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(167409079868000));
+    }
+}
@@ -0,0 +1,79 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    params::ParamError,
+    parse::{alt, lines, map, pair, parse, preceded, separated, tag, terminated, word, ParseError},
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A parameter's value isn't one the solution can use.
+    Param(ParamError),
+    /// A module the puzzle relies on isn't in the configuration.
+    NoModule(String),
+    /// Nothing sends pulses to the module.
+    Unfed(String),
+    /// One of the counters feeding the target doesn't start back at all
+    /// flip-flops off, so the presses can't be worked out from their periods.
+    OffsetCounter,
+    /// The presses needed don't fit in a `usize`.
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Param(err) => err.fmt(f),
+            Error::NoModule(name) => write!(f, "no module is called {name}"),
+            Error::Unfed(name) => write!(f, "nothing sends pulses to {name}"),
+            Error::OffsetCounter => write!(f, "a counter doesn't start from zero"),
+            Error::Overflow => write!(f, "the button presses overflow"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Param(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+/// A module's kind, its name and the names of its destinations.
+type Declaration<'a> = (Kind, &'a str, Vec<&'a str>);
+
+fn parse_declarations(input: &str) -> Result<Vec<Declaration<'_>>, Error> {
+    let module = alt(
+        map(tag("broadcaster"), |name| (Kind::Broadcaster, name)),
+        alt(
+            map(preceded(tag("%"), word()), |name| (Kind::FlipFlop, name)),
+            map(preceded(tag("&"), word()), |name| (Kind::Conjunction, name)),
+        ),
+    );
+    let declaration = map(
+        pair(
+            terminated(module, tag(" -> ")),
+            separated(word(), tag(", ")),
+        ),
+        |((kind, name), destinations)| (kind, name, destinations),
+    );
+    Ok(parse(input, lines(declaration))?)
+}
@@ -0,0 +1,181 @@
+use crate::{Error, Kind};
+use aoc_common::{
+    graph::{Graph, Interner, NodeId},
+    Params, Solution,
+};
+use std::collections::VecDeque;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum SignalType {
+    High,
+    Low,
+}
+
+struct SignalResult {
+    signal_count: usize,
+    signal_type: SignalType,
+    to_queue: Vec<NodeId>,
+}
+
+#[derive(Debug)]
+struct Module {
+    module_type: Option<ModuleType>,
+    emitting: SignalType,
+}
+
+#[derive(Debug)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop(bool),
+    Conjunction,
+}
+
+/// Every module, with an edge to each of its destinations, and the
+/// broadcaster. Destinations that are never declared get a module with no
+/// type.
+pub struct Modules {
+    graph: Graph<Module, ()>,
+    broadcaster: NodeId,
+}
+
+fn parse_modules(input: &str) -> Result<Modules, Error> {
+    let mut graph = Graph::directed();
+    let mut names = Interner::new();
+    let untyped = |_: &&str| Module {
+        module_type: None,
+        emitting: SignalType::Low,
+    };
+    for (kind, module_name, destinations) in crate::parse_declarations(input)? {
+        let module_type = match kind {
+            Kind::FlipFlop => ModuleType::FlipFlop(false),
+            Kind::Conjunction => ModuleType::Conjunction,
+            Kind::Broadcaster => ModuleType::Broadcaster,
+        };
+        let module = names.intern(&mut graph, module_name, untyped);
+        graph[module].module_type = Some(module_type);
+        for destination_name in destinations {
+            let destination = names.intern(&mut graph, destination_name, untyped);
+            graph.add_edge(module, destination, ());
+        }
+    }
+    let broadcaster = names
+        .id("broadcaster")
+        .ok_or_else(|| Error::NoModule("broadcaster".to_string()))?;
+    Ok(Modules { graph, broadcaster })
+}
+
+fn send_signal(graph: &mut Graph<Module, ()>, module: NodeId) -> Option<SignalResult> {
+    let signal_type = match graph[module].module_type.as_ref()? {
+        ModuleType::Broadcaster => SignalType::Low,
+        ModuleType::FlipFlop(on) => {
+            if *on {
+                SignalType::Low
+            } else {
+                SignalType::High
+            }
+        }
+        ModuleType::Conjunction => {
+            if graph
+                .predecessors(module)
+                .all(|from| graph[from].emitting == SignalType::High)
+            {
+                SignalType::Low
+            } else {
+                SignalType::High
+            }
+        }
+    };
+    if let Some(ModuleType::FlipFlop(on)) = &mut graph[module].module_type {
+        *on = !*on;
+    }
+    let signal_count = graph.out_degree(module);
+    let to_queue = graph
+        .successors(module)
+        .filter(|&destination| {
+            signal_type == SignalType::Low
+                || !matches!(
+                    graph[destination].module_type,
+                    Some(ModuleType::FlipFlop(_))
+                )
+        })
+        .collect();
+    graph[module].emitting = signal_type;
+    Some(SignalResult {
+        signal_count,
+        signal_type,
+        to_queue,
+    })
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(pulse_product(parse_modules(input)?))
+}
+
+/// The low pulses times the high pulses sent over 1000 button presses.
+fn pulse_product(
+    Modules {
+        mut graph,
+        broadcaster,
+    }: Modules,
+) -> usize {
+    let mut low_count = 0;
+    let mut high_count = 0;
+    for _ in 0..1000 {
+        low_count += 1;
+        let mut module_signal_queue: VecDeque<NodeId> = VecDeque::from([broadcaster]);
+        while let Some(to_signal) = module_signal_queue.pop_front() {
+            let Some(signal_result) = send_signal(&mut graph, to_signal) else {
+                continue;
+            };
+            match signal_result.signal_type {
+                SignalType::High => {
+                    high_count += signal_result.signal_count;
+                }
+                SignalType::Low => {
+                    low_count += signal_result.signal_count;
+                }
+            }
+            module_signal_queue.extend(signal_result.to_queue);
+        }
+    }
+    low_count * high_count
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Modules;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Modules, Error> {
+        parse_modules(input)
+    }
+
+    fn solve(modules: Modules, _: &Params) -> Result<usize, Error> {
+        Ok(pulse_product(modules))
+    }
+}
+
+const EXAMPLE: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(11687500));
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{Error, Kind};
+use aoc_common::{
+    cycle,
+    graph::{Graph, Interner, NodeId},
+    math, search, Param, Params, Solution,
+};
+use std::collections::VecDeque;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum SignalType {
+    High,
+    Low,
+}
+
+#[derive(Debug, Clone)]
+struct Module {
+    module_type: Option<ModuleType>,
+    emitting: SignalType,
+}
+
+#[derive(Debug, Clone)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop(bool),
+    Conjunction,
+}
+
+/// Every module, with an edge to each of its destinations, the interned
+/// names and the broadcaster. Destinations that are never declared get a
+/// module with no type.
+pub struct Modules<'a> {
+    graph: Graph<Module, ()>,
+    names: Interner<&'a str>,
+    broadcaster: NodeId,
+}
+
+fn parse_modules(input: &str) -> Result<Modules<'_>, Error> {
+    let mut graph = Graph::directed();
+    let mut names = Interner::new();
+    let untyped = |_: &&str| Module {
+        module_type: None,
+        emitting: SignalType::Low,
+    };
+    for (kind, module_name, destinations) in crate::parse_declarations(input)? {
+        let module_type = match kind {
+            Kind::FlipFlop => ModuleType::FlipFlop(false),
+            Kind::Conjunction => ModuleType::Conjunction,
+            Kind::Broadcaster => ModuleType::Broadcaster,
+        };
+        let module = names.intern(&mut graph, module_name, untyped);
+        graph[module].module_type = Some(module_type);
+        for destination_name in destinations {
+            let destination = names.intern(&mut graph, destination_name, untyped);
+            graph.add_edge(module, destination, ());
+        }
+    }
+    let broadcaster = names
+        .id("broadcaster")
+        .ok_or_else(|| Error::NoModule("broadcaster".to_string()))?;
+    Ok(Modules {
+        graph,
+        names,
+        broadcaster,
+    })
+}
+
+/// Sends `module`'s pulse, giving the destinations that react to it.
+fn send_signal(graph: &mut Graph<Module, ()>, module: NodeId) -> Option<Vec<NodeId>> {
+    let signal_type = match graph[module].module_type.as_ref()? {
+        ModuleType::Broadcaster => SignalType::Low,
+        ModuleType::FlipFlop(on) => {
+            if *on {
+                SignalType::Low
+            } else {
+                SignalType::High
+            }
+        }
+        ModuleType::Conjunction => {
+            if graph
+                .predecessors(module)
+                .all(|from| graph[from].emitting == SignalType::High)
+            {
+                SignalType::Low
+            } else {
+                SignalType::High
+            }
+        }
+    };
+    if let Some(ModuleType::FlipFlop(on)) = &mut graph[module].module_type {
+        *on = !*on;
+    }
+    let to_queue = graph
+        .successors(module)
+        .filter(|&destination| {
+            signal_type == SignalType::Low
+                || !matches!(
+                    graph[destination].module_type,
+                    Some(ModuleType::FlipFlop(_))
+                )
+        })
+        .collect();
+    graph[module].emitting = signal_type;
+    Some(to_queue)
+}
+
+fn press_button(graph: &mut Graph<Module, ()>, broadcaster: NodeId) {
+    let mut module_signal_queue: VecDeque<NodeId> = VecDeque::from([broadcaster]);
+    while let Some(to_signal) = module_signal_queue.pop_front() {
+        if let Some(to_queue) = send_signal(graph, to_signal) {
+            module_signal_queue.extend(to_queue);
+        }
+    }
+}
+
+pub fn part2(input: &str, target_name: &str) -> Result<usize, Error> {
+    fewest_presses(parse_modules(input)?, target_name)
+}
+
+/// The fewest button presses that send `target_name` a low pulse.
+fn fewest_presses(
+    Modules {
+        graph,
+        names,
+        broadcaster,
+    }: Modules<'_>,
+    target_name: &str,
+) -> Result<usize, Error> {
+    let target = names
+        .id(target_name)
+        .ok_or_else(|| Error::NoModule(target_name.to_string()))?;
+    let before_target = graph
+        .predecessors(target)
+        .next()
+        .ok_or_else(|| Error::Unfed(target_name.to_string()))?;
+    let pressed = |graph: &Graph<Module, ()>| {
+        let mut graph = graph.clone();
+        press_button(&mut graph, broadcaster);
+        graph
+    };
+    // Each module feeding the one before the target is the end of a
+    // separate counter: the flip-flops behind it, which only the
+    // broadcaster ties to the others. It sends a high pulse when the count
+    // overflows back to all flip-flops off, so how often that happens is the
+    // period of those flip-flops' states.
+    let cycle_lengths = graph.predecessors(before_target).map(|counter_end| {
+        let counter = search::bfs(
+            counter_end,
+            |&module| {
+                graph
+                    .predecessors(module)
+                    .filter(|&from| from != broadcaster)
+                    .collect::<Vec<NodeId>>()
+            },
+            |_| false,
+        );
+        let flip_flops: Vec<NodeId> = graph
+            .node_ids()
+            .filter(|&module| {
+                counter.contains(&module)
+                    && matches!(graph[module].module_type, Some(ModuleType::FlipFlop(_)))
+            })
+            .collect();
+        let cycle = cycle::find_cycle_by_key(graph.clone(), pressed, |graph| {
+            flip_flops
+                .iter()
+                .map(|&flip_flop| {
+                    matches!(
+                        graph[flip_flop].module_type,
+                        Some(ModuleType::FlipFlop(true))
+                    )
+                })
+                .collect::<Vec<bool>>()
+        });
+        if cycle.prefix != 0 {
+            return Err(Error::OffsetCounter);
+        }
+        Ok(cycle.period as u64)
+    });
+    presses(cycle_lengths.collect::<Result<Vec<u64>, Error>>()?)
+}
+
+/// The first press on which counters with these periods all overflow
+/// together.
+fn presses(periods: Vec<u64>) -> Result<usize, Error> {
+    let presses = math::lcm_all(periods).ok_or(Error::Overflow)?;
+    usize::try_from(presses).map_err(|_| Error::Overflow)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = None;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "target",
+        default: "rx",
+        about: "the module that must get a single low pulse",
+    }];
+
+    type Input<'a> = Modules<'a>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Modules<'_>, Error> {
+        parse_modules(input)
+    }
+
+    fn solve(modules: Modules<'_>, params: &Params) -> Result<usize, Error> {
+        fewest_presses(modules, &params.get::<String>("target")?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[rustfmt::skip]
+    fn part2_test() {
+        // Counters of 3 and 5 presses, each reset by a conjunction and
+        // inverted into the one before the target.
+        let result = part2("\
+broadcaster -> a0, b0
+%a0 -> a1, ca
+%a1 -> ca
+&ca -> a0, ia
+&ia -> hub
+%b0 -> b1, cb
+%b1 -> b2
+%b2 -> cb
+&cb -> b0, b1, ib
+&ib -> hub
+&hub -> target",
+            "target",
+        );
+        assert_eq!(result, Ok(15));
+
+        // `h` hears from `r` before the longer way round through `p` on the
+        // first press only, so `f` is turned on then and never turned off.
+        let result = part2("\
+broadcaster -> r, p0
+&r -> h
+&p0 -> p1
+&p1 -> p
+&p -> h
+&h -> g
+&g -> f
+%f -> hub
+&hub -> target",
+            "target",
+        );
+        assert_eq!(result, Err(Error::OffsetCounter));
+
+        assert_eq!(presses(vec![1 << 40, 3 << 40]), Ok(3 << 40));
+        assert_eq!(presses(vec![u64::MAX, 2]), Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,62 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    params::ParamError,
+    parse::{grid, parse, ParseError},
+    Grid, Position,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A parameter's value isn't one the solution can use.
+    Param(ParamError),
+    /// The map doesn't mark where the elf starts with an `S`.
+    MissingStart,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Param(err) => err.fmt(f),
+            Error::MissingStart => write!(f, "no starting position S"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Param(err)
+    }
+}
+
+/// The map, with only `.`, `#` and `S` on it, and where the `S` is.
+pub struct Garden {
+    pub grid: Grid<char>,
+    pub start: Position,
+}
+
+fn parse_garden(input: &str) -> Result<Garden, Error> {
+    let garden = parse(
+        input,
+        grid("a garden plot, rock or S", |c| {
+            matches!(c, '.' | '#' | 'S').then_some(c)
+        }),
+    )?;
+    let start = garden.find(|&c| c == 'S').ok_or(Error::MissingStart)?;
+    Ok(Garden {
+        grid: garden,
+        start: start.into(),
+    })
+}
@@ -0,0 +1,77 @@
+use crate::{parse_garden, Error, Garden};
+use aoc_common::{search, Direction, Param, Params, Solution};
+
+pub fn part1(input: &str, steps: usize) -> Result<usize, Error> {
+    Ok(reachable(&parse_garden(input)?, steps))
+}
+
+/// How many plots the elf can be on after exactly `steps` steps.
+fn reachable(Garden { grid, start }: &Garden, steps: usize) -> usize {
+    let start = *start;
+    let distances = search::bfs(
+        start,
+        |&position| {
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |direction| grid.step(position, direction))
+                .filter(move |&next| grid[next] != '#')
+        },
+        |_| false,
+    );
+    // The elf can waste steps going back and forth, so any plot with the
+    // same parity as `steps` within reach is a place it can end up.
+    grid.positions()
+        .filter_map(|position| distances.cost(&position.into()))
+        .filter(|&distance| distance <= steps && distance % 2 == steps % 2)
+        .count()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        default: "64",
+        about: "how many steps the elf takes",
+    }];
+
+    type Input<'a> = Garden;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Garden, Error> {
+        parse_garden(input)
+    }
+
+    fn solve(garden: Garden, params: &Params) -> Result<usize, Error> {
+        Ok(reachable(&garden, params.get("steps")?))
+    }
+}
+
+const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE, 6);
+        assert_eq!(result, Ok(16));
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{parse_garden, Error, Garden};
+use aoc_common::{
+    params::ParamError,
+    search::{self, Dense},
+    Direction, Param, Params, Point, Solution,
+};
+
+#[derive(Debug, Clone)]
+struct StepSolution {
+    steps: usize,
+    possible_pots: usize,
+}
+
+pub fn part2(
+    input: &str,
+    step_input: usize,
+    parabola_count: usize,
+    cycle_valid_after: usize,
+) -> Result<usize, Error> {
+    reachable(
+        &parse_garden(input)?,
+        step_input,
+        parabola_count,
+        cycle_valid_after,
+    )
+}
+
+/// How many plots of the endlessly repeated garden the elf can be on after
+/// exactly `step_input` steps.
+fn reachable(
+    Garden { grid, start }: &Garden,
+    step_input: usize,
+    parabola_count: usize,
+    cycle_valid_after: usize,
+) -> Result<usize, Error> {
+    if parabola_count == 0 {
+        return Err(Error::Param(ParamError::Invalid {
+            name: "period".to_owned(),
+            value: parabola_count.to_string(),
+            reason: "the plots must repeat after at least 1 step".to_owned(),
+        }));
+    }
+    if cycle_valid_after > step_input {
+        return Err(Error::Param(ParamError::Invalid {
+            name: "settled-after".to_owned(),
+            value: cycle_valid_after.to_string(),
+            reason: format!("the count must settle within the {step_input} steps"),
+        }));
+    }
+    let first_x = (step_input - cycle_valid_after) % (parabola_count * 2) + cycle_valid_after;
+    let parabola_xs: [usize; 3] = [0, 1, 2].map(|nth| first_x + nth * parabola_count * 2);
+    let steps_to_do = parabola_xs[2];
+    let start = Point::new(start.row as isize, start.col as isize);
+    // No walk to a plot is shorter than its Manhattan distance from the
+    // start, so the search keeps to the plots within `steps_to_do` of it
+    // that way, and to the square around them.
+    let reach = steps_to_do as isize;
+    let side = 2 * reach + 1;
+    let index = move |point: &Point| {
+        ((point.row - start.row + reach) * side + point.col - start.col + reach) as usize
+    };
+    let distances = search::bfs_in(
+        Dense::new((side * side) as usize, index),
+        start,
+        |&point| {
+            Direction::ALL
+                .into_iter()
+                .map(move |direction| point.step(direction, 1))
+                .filter(move |&next| {
+                    next.manhattan_distance(start) <= steps_to_do
+                        && grid[grid.wrap((next.row, next.col))] != '#'
+                })
+        },
+        |_| false,
+    );
+    let mut parabola_points: Vec<StepSolution> = parabola_xs
+        .into_iter()
+        .map(|parabola_step_point| StepSolution {
+            steps: parabola_step_point,
+            possible_pots: 0,
+        })
+        .collect();
+    let within_reach = (-reach..=reach).flat_map(|row| {
+        (-reach..=reach).map(move |col| Point::new(start.row + row, start.col + col))
+    });
+    for distance in within_reach.filter_map(|point| distances.cost(&point)) {
+        // The elf can waste steps going back and forth, so a plot counts for
+        // every number of steps at least its distance with the same parity.
+        for step_solution in &mut parabola_points {
+            if distance <= step_solution.steps && distance % 2 == step_solution.steps % 2 {
+                step_solution.possible_pots += 1;
+            }
+        }
+    }
+    Ok(math::calculate_parabola(
+        &parabola_points[0],
+        &parabola_points[1],
+        &parabola_points[2],
+    )
+    .f_of(step_input))
+}
+
+mod math {
+    use super::StepSolution;
+
+    pub struct Parabola {
+        a: f64,
+        b: f64,
+        c: f64,
+    }
+
+    impl Parabola {
+        pub fn f_of(&self, x: usize) -> usize {
+            (self.a * (x.pow(2) as f64) + self.b * (x as f64) + self.c).round() as usize
+        }
+    }
+
+    // https://stackoverflow.com/questions/717762/how-to-calculate-the-vertex-of-a-parabola-given-three-points
+    pub fn calculate_parabola(p1: &StepSolution, p2: &StepSolution, p3: &StepSolution) -> Parabola {
+        let x1 = p1.steps as f64;
+        let y1 = p1.possible_pots as f64;
+        let x2 = p2.steps as f64;
+        let y2 = p2.possible_pots as f64;
+        let x3 = p3.steps as f64;
+        let y3 = p3.possible_pots as f64;
+        let denom = (x1 - x2) * (x1 - x3) * (x2 - x3);
+        let a = (x3 * (y2 - y1) + x2 * (y1 - y3) + x1 * (y3 - y2)) / denom;
+        let b = (x3 * x3 * (y1 - y2) + x2 * x2 * (y3 - y1) + x1 * x1 * (y2 - y3)) / denom;
+        let c = (x2 * x3 * (x2 - x3) * y1 + x3 * x1 * (x3 - x1) * y2 + x1 * x2 * (x1 - x2) * y3)
+            / denom;
+        Parabola { a, b, c }
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = None;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "steps",
+            default: "26501365",
+            about: "how many steps the elf takes",
+        },
+        Param {
+            name: "period",
+            default: "131",
+            about: "steps after which the reachable plots repeat, the width of the map",
+        },
+        Param {
+            name: "settled-after",
+            default: "0",
+            about: "steps before the count of plots starts growing quadratically",
+        },
+    ];
+
+    type Input<'a> = Garden;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Garden, Error> {
+        parse_garden(input)
+    }
+
+    fn solve(garden: Garden, params: &Params) -> Result<usize, Error> {
+        reachable(
+            &garden,
+            params.get("steps")?,
+            params.get("period")?,
+            params.get("settled-after")?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[rustfmt::skip]
+    fn part2_test() {
+        let grid = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+        let curried_part2 = |step_input| part2(grid, step_input, 11, 40);
+        assert_eq!(curried_part2(50), Ok(1594));
+        assert_eq!(curried_part2(100), Ok(6536));
+        assert_eq!(curried_part2(500), Ok(167004));
+        assert_eq!(curried_part2(1000), Ok(668697));
+        assert_eq!(curried_part2(5000), Ok(16733044));
+        assert!(matches!(
+            part2(grid, 50, 0, 40),
+            Err(Error::Param(ParamError::Invalid { name, .. })) if name == "period"
+        ));
+        assert!(matches!(
+            part2(grid, 30, 11, 40),
+            Err(Error::Param(ParamError::Invalid { name, .. })) if name == "settled-after"
+        ));
+    }
+}
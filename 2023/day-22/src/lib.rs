@@ -0,0 +1,63 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::parse::{lines, pair, parse, preceded, tag, terminated, unsigned, ParseError};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// The brick's ends differ along more than one axis.
+    Diagonal {
+        line: usize,
+    },
+    /// The brick reaches down into the ground at `z` 0.
+    Underground {
+        line: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Diagonal { line } => write!(f, "{line}:1: brick isn't a straight line"),
+            Error::Underground { line } => write!(f, "{line}:1: brick is below z 1"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Both ends of every brick snapshot, as `x`, `y` and `z`, none of them in
+/// the ground.
+fn parse_ends(input: &str) -> Result<Vec<[[usize; 3]; 2]>, Error> {
+    let end = || {
+        pair(
+            terminated(unsigned::<usize>(), tag(",")),
+            pair(
+                terminated(unsigned::<usize>(), tag(",")),
+                unsigned::<usize>(),
+            ),
+        )
+    };
+    let brick = pair(end(), preceded(tag("~"), end()));
+    let bricks = parse(input, lines(brick))?;
+    bricks
+        .into_iter()
+        .enumerate()
+        .map(|(index, ((x1, (y1, z1)), (x2, (y2, z2))))| {
+            if z1 == 0 || z2 == 0 {
+                Err(Error::Underground { line: index + 1 })
+            } else {
+                Ok([[x1, y1, z1], [x2, y2, z2]])
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,168 @@
+use crate::Error;
+use aoc_common::{
+    graph::{Graph, NodeId},
+    Params, Solution,
+};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug)]
+struct BrickEnd {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum Orientation {
+    AlongX,
+    AlongY,
+    AlongZ,
+    None,
+}
+impl Orientation {
+    /// The axis the brick lies along, or `None` if it doesn't lie along
+    /// just one.
+    fn from_brick_ends(e1: &BrickEnd, e2: &BrickEnd) -> Option<Self> {
+        match (e1.x != e2.x, e1.y != e2.y, e1.z != e2.z) {
+            (false, false, false) => Some(Orientation::None),
+            (true, false, false) => Some(Orientation::AlongX),
+            (false, true, false) => Some(Orientation::AlongY),
+            (false, false, true) => Some(Orientation::AlongZ),
+            _ => None,
+        }
+    }
+}
+
+/// A brick's ends and which way it lies.
+#[derive(Debug)]
+pub struct Brick {
+    upper_end: BrickEnd,
+    lower_end: BrickEnd,
+    orientation: Orientation,
+}
+impl Brick {
+    fn from(mut upper_end: BrickEnd, mut lower_end: BrickEnd) -> Option<Self> {
+        if upper_end.z < lower_end.z || upper_end.y < lower_end.y || upper_end.x < lower_end.x {
+            std::mem::swap(&mut upper_end, &mut lower_end);
+        }
+        let orientation = Orientation::from_brick_ends(&upper_end, &lower_end)?;
+        Some(Brick {
+            upper_end,
+            lower_end,
+            orientation,
+        })
+    }
+    fn point_xys(&self) -> Box<dyn Iterator<Item = [usize; 2]> + '_> {
+        match self.orientation {
+            Orientation::AlongX => Box::new(
+                (self.lower_end.x..=self.upper_end.x).map(|point_x| [point_x, self.lower_end.y]),
+            ),
+            Orientation::AlongY => Box::new(
+                (self.lower_end.y..=self.upper_end.y).map(|point_y| [self.lower_end.x, point_y]),
+            ),
+            Orientation::AlongZ | Orientation::None => {
+                Box::new(std::iter::once([self.lower_end.x, self.lower_end.y]))
+            }
+        }
+    }
+}
+
+fn parse_bricks(input: &str) -> Result<Vec<Brick>, Error> {
+    crate::parse_ends(input)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, ends)| {
+            let [e1, e2] = ends.map(|[x, y, z]| BrickEnd { x, y, z });
+            Brick::from(e1, e2).ok_or(Error::Diagonal { line: index + 1 })
+        })
+        .collect()
+}
+
+/// Drops the bricks as far as they go, lowest first, with an edge from each
+/// brick to every brick that comes to rest on it.
+fn fall(mut bricks: Vec<Brick>) -> Graph<Brick, ()> {
+    bricks.sort_by_key(|brick| brick.lower_end.z);
+    let mut graph = Graph::directed();
+    // The top of the highest brick so far above each (x, y), and which
+    // brick that is.
+    let mut tops: HashMap<[usize; 2], (usize, NodeId)> = HashMap::new();
+    for mut brick in bricks {
+        let below: Vec<(usize, NodeId)> = brick
+            .point_xys()
+            .filter_map(|point_xy| tops.get(&point_xy).copied())
+            .collect();
+        let rest_on = below.iter().map(|&(z, _)| z).max().unwrap_or(0);
+        let diff = brick.lower_end.z - rest_on - 1;
+        brick.upper_end.z -= diff;
+        brick.lower_end.z -= diff;
+        let top = brick.upper_end.z;
+        let point_xys: Vec<[usize; 2]> = brick.point_xys().collect();
+        let id = graph.add_node(brick);
+        for (_, held_by) in below.into_iter().filter(|&(z, _)| z == rest_on).unique() {
+            graph.add_edge(held_by, id, ());
+        }
+        for point_xy in point_xys {
+            tops.insert(point_xy, (top, id));
+        }
+    }
+    graph
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Ok(safe_to_disintegrate(parse_bricks(input)?))
+}
+
+/// How many bricks could be taken away without any other falling.
+fn safe_to_disintegrate(bricks: Vec<Brick>) -> usize {
+    let bricks = fall(bricks);
+    bricks
+        .node_ids()
+        .filter(|&brick| {
+            bricks
+                .successors(brick)
+                .all(|brick_hold| bricks.in_degree(brick_hold) > 1)
+        })
+        .count()
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 22;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<Brick>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Brick>, Error> {
+        parse_bricks(input)
+    }
+
+    fn solve(bricks: Vec<Brick>, _: &Params) -> Result<usize, Error> {
+        Ok(safe_to_disintegrate(bricks))
+    }
+}
+
+const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(5));
+    }
+}
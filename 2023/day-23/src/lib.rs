@@ -0,0 +1,68 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    parse::{grid, parse, ParseError},
+    Grid,
+};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// The map isn't walled in apart from the way in at the top left and
+    /// ways out along the bottom.
+    NotWalledIn,
+    /// The slopes lead round in a loop, so there's no longest hike.
+    Loop,
+    /// No hike gets from the top to the bottom.
+    NoPath,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::NotWalledIn => write!(f, "the map isn't walled in"),
+            Error::Loop => write!(f, "the slopes go round in a loop"),
+            Error::NoPath => write!(f, "no way gets to the bottom"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// The map, checked to have forest all round it but for the start at the
+/// second tile of the top row and paths out along the bottom, so the hikes
+/// never step off it.
+fn parse_trails(input: &str) -> Result<Grid<char>, Error> {
+    let trails = parse(
+        input,
+        grid("a path, forest or > or v slope", |c| {
+            matches!(c, '.' | '#' | '>' | 'v').then_some(c)
+        }),
+    )?;
+    let (height, width) = (trails.height(), trails.width());
+    let walled_in = height >= 3
+        && width >= 3
+        && trails.iter().all(|((row, column), &tile)| {
+            if (row, column) == (0, 1) {
+                tile == '.'
+            } else if row == 0 || column == 0 || column == width - 1 {
+                tile == '#'
+            } else {
+                row < height - 1 || tile != '>' && tile != 'v'
+            }
+        });
+    if walled_in {
+        Ok(trails)
+    } else {
+        Err(Error::NotWalledIn)
+    }
+}
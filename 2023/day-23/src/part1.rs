@@ -0,0 +1,154 @@
+use crate::{parse_trails, Error};
+use aoc_common::{
+    graph::{Graph, Interner},
+    Direction, Grid, Params, Solution,
+};
+use std::collections::VecDeque;
+
+type Position = [usize; 2];
+
+/// A stretch of path from one slope to the next, and how many tiles long
+/// it is counting the slope at its end.
+struct Segment {
+    start_pos: Position,
+    weight: usize,
+}
+
+/// Every stretch of path, with an edge from each to the stretches its
+/// slopes lead down to.
+fn wdag(grid: Grid<char>, root_pos: Position) -> Graph<Segment, ()> {
+    let mut graph = Graph::directed();
+    let mut start_positions = Interner::new();
+    let new_segment = |&start_pos: &Position| Segment {
+        start_pos,
+        weight: 0,
+    };
+    let root = start_positions.intern(&mut graph, root_pos, new_segment);
+    let mut queue = VecDeque::from([root]);
+    while let Some(node) = queue.pop_front() {
+        let [mut i, mut j] = graph[node].start_pos;
+        let mut prev_direction = None;
+        let mut weight = 1;
+        while i + 1 != grid.height() {
+            if prev_direction != Some(Direction::Down)
+                && [i, j] != root_pos
+                && grid[(i - 1, j)] == '.'
+            {
+                i -= 1;
+                prev_direction = Some(Direction::Up);
+            } else if prev_direction != Some(Direction::Right) && grid[(i, j - 1)] == '.' {
+                j -= 1;
+                prev_direction = Some(Direction::Left);
+            } else if prev_direction != Some(Direction::Up) && grid[(i + 1, j)] == '.' {
+                i += 1;
+                prev_direction = Some(Direction::Down);
+            } else if prev_direction != Some(Direction::Left) && grid[(i, j + 1)] == '.' {
+                j += 1;
+                prev_direction = Some(Direction::Right);
+            } else {
+                break;
+            }
+            weight += 1;
+        }
+        if i + 1 != grid.height() {
+            let mut try_from_start_position = |next_start_pos| {
+                let next = match start_positions.id(&next_start_pos) {
+                    Some(next) => next,
+                    None => {
+                        let next = start_positions.intern(&mut graph, next_start_pos, new_segment);
+                        queue.push_back(next);
+                        next
+                    }
+                };
+                graph.add_edge(node, next, ());
+            };
+            if grid[(i, j + 1)] == '>' {
+                try_from_start_position([i, j + 2]);
+            }
+            if grid[(i + 1, j)] == 'v' {
+                try_from_start_position([i + 2, j]);
+            }
+            weight += 1;
+        }
+        graph[node].weight = weight;
+    }
+    graph
+}
+
+fn longest_path(graph: &Graph<Segment, ()>) -> Result<usize, Error> {
+    let topological_sorting = graph.topological_sort().ok_or(Error::Loop)?;
+    // The most tiles walked before getting to each segment.
+    let mut longest_to: Vec<usize> = vec![0; graph.node_count()];
+    let mut longest = 0;
+    for node in topological_sorting {
+        let through = longest_to[node.index()] + graph[node].weight;
+        if graph.out_degree(node) == 0 {
+            longest = longest.max(through);
+        }
+        for next in graph.successors(node) {
+            longest_to[next.index()] = longest_to[next.index()].max(through);
+        }
+    }
+    Ok(longest)
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    longest_path(&wdag(parse_trails(input)?, [1, 1]))
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Grid<char>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Grid<char>, Error> {
+        parse_trails(input)
+    }
+
+    fn solve(grid: Grid<char>, _: &Params) -> Result<usize, Error> {
+        longest_path(&wdag(grid, [1, 1]))
+    }
+}
+
+const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(94));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{parse_trails, Error};
+use aoc_common::{
+    graph::{Graph, Interner, NodeId},
+    Direction, Grid, Params, Solution,
+};
+use std::collections::VecDeque;
+
+type Position = [usize; 2];
+
+/// The junctions, each named by where it starts, joined by the length of
+/// the path between them. The slopes don't matter any more, so the edges
+/// go both ways. `None` if no path reaches the bottom.
+fn weighted_graph(
+    grid: Grid<char>,
+    root_pos: Position,
+) -> Option<(Graph<Position, usize>, [NodeId; 2])> {
+    let mut graph = Graph::undirected();
+    let mut start_positions = Interner::new();
+    let root = start_positions.intern(&mut graph, root_pos, |&start_pos| start_pos);
+    let mut queue = VecDeque::from([root]);
+    let end;
+    'outer: loop {
+        let node = queue.pop_front()?;
+        let [mut i, mut j] = graph[node];
+        let mut prev_direction = None;
+        let mut weight = 1;
+        loop {
+            if i + 1 == grid.height() {
+                end = graph.add_node([i, j]);
+                graph.add_edge(node, end, weight);
+                break 'outer;
+            }
+            if prev_direction != Some(Direction::Down)
+                && [i, j] != root_pos
+                && grid[(i - 1, j)] == '.'
+            {
+                i -= 1;
+                prev_direction = Some(Direction::Up);
+            } else if prev_direction != Some(Direction::Right) && grid[(i, j - 1)] == '.' {
+                j -= 1;
+                prev_direction = Some(Direction::Left);
+            } else if prev_direction != Some(Direction::Up) && grid[(i + 1, j)] == '.' {
+                i += 1;
+                prev_direction = Some(Direction::Down);
+            } else if prev_direction != Some(Direction::Left) && grid[(i, j + 1)] == '.' {
+                j += 1;
+                prev_direction = Some(Direction::Right);
+            } else {
+                break;
+            }
+            weight += 1;
+        }
+        weight += 1;
+        let mut discover_adj = |next_pos| {
+            let adj = match start_positions.id(&next_pos) {
+                Some(adj) => adj,
+                None => {
+                    let adj = start_positions.intern(&mut graph, next_pos, |&start_pos| start_pos);
+                    queue.push_back(adj);
+                    adj
+                }
+            };
+            graph.add_edge(node, adj, weight);
+        };
+        if grid[(i, j + 1)] == '>' {
+            discover_adj([i, j + 2]);
+        }
+        if grid[(i + 1, j)] == 'v' {
+            discover_adj([i + 2, j]);
+        }
+    }
+    // A junction with only two ways out is just a bend in a longer path, and
+    // of two paths between the same junctions only the longer one matters.
+    for node in graph.node_ids().collect::<Vec<NodeId>>() {
+        graph.smooth(node, |a, b| a + b, usize::max);
+    }
+    Some((graph, [root, end]))
+}
+
+/// The longest way from `node` to `end` that doesn't go through anything
+/// in `seen` twice, or `None` if there's no way at all.
+fn longest_path(
+    graph: &Graph<Position, usize>,
+    node: NodeId,
+    end: NodeId,
+    seen: &mut [bool],
+) -> Option<usize> {
+    if node == end {
+        return Some(0);
+    }
+    seen[node.index()] = true;
+    let longest = graph
+        .edges_from(node)
+        .filter_map(|(adj, &weight)| {
+            if seen[adj.index()] {
+                None
+            } else {
+                Some(weight + longest_path(graph, adj, end, seen)?)
+            }
+        })
+        .max();
+    seen[node.index()] = false;
+    longest
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    longest_hike(parse_trails(input)?)
+}
+
+/// The steps of the longest hike that never steps on a tile twice.
+fn longest_hike(grid: Grid<char>) -> Result<usize, Error> {
+    let (graph, [root, end]) = weighted_graph(grid, [1, 1]).ok_or(Error::NoPath)?;
+    longest_path(&graph, root, end, &mut vec![false; graph.node_count()]).ok_or(Error::NoPath)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Grid<char>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Grid<char>, Error> {
+        parse_trails(input)
+    }
+
+    fn solve(grid: Grid<char>, _: &Params) -> Result<usize, Error> {
+        longest_hike(grid)
+    }
+}
+
+const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(154));
+    }
+}
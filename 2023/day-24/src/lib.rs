@@ -0,0 +1,49 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_common::{
+    params::ParamError,
+    parse::{separated, signed, tag, try_map, ParseError, Parser},
+};
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// A parameter's value isn't one the solution can use.
+    Param(ParamError),
+    /// No rock thrown from a whole-numbered position at a whole-numbered
+    /// velocity hits every hailstone, or finding it overflows.
+    NoThrow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+            Error::Param(err) => err.fmt(f),
+            Error::NoThrow => write!(f, "no single throw hits every hailstone"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Param(err)
+    }
+}
+
+/// `x, y, z`.
+fn triple<'a, T: FromStr>() -> impl Parser<'a, [T; 3]> {
+    try_map(separated(signed(), tag(", ")), |numbers: Vec<T>| {
+        numbers.try_into().map_err(|_| "three numbers".to_string())
+    })
+}
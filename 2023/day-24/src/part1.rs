@@ -0,0 +1,116 @@
+use crate::{triple, Error};
+use aoc_common::{
+    parse::{lines, map, pair, parse, tag, terminated},
+    Param, Params, Solution,
+};
+use itertools::Itertools;
+
+/// A hailstone's position and velocity.
+pub struct Hailstone {
+    pos: [f64; 3],
+    vel: [f64; 3],
+}
+
+impl Hailstone {
+    fn path_intersects_future(&self, other: &Self, lower_bound: f64, upper_bound: f64) -> bool {
+        #[allow(unused_variables)]
+        let [px1, py1, pz1] = self.pos;
+        #[allow(unused_variables)]
+        let [vx1, vy1, vz1] = self.vel;
+        #[allow(unused_variables)]
+        let [px2, py2, pz2] = other.pos;
+        #[allow(unused_variables)]
+        let [vx2, vy2, vz2] = other.vel;
+        let m1 = vy1 / vx1;
+        let m2 = vy2 / vx2;
+        let intersection_x = (m1 * px1 - m2 * px2 + py2 - py1) / (m1 - m2);
+        let intersection_y = m1 * intersection_x - m1 * px1 + py1;
+        let intersections_in_bound = intersection_x >= lower_bound
+            && upper_bound >= intersection_x
+            && intersection_y >= lower_bound
+            && upper_bound >= intersection_y;
+        let first_is_valid = (intersection_x - px1).signum() == vx1.signum();
+        let second_is_valid = (intersection_x - px2).signum() == vx2.signum();
+        intersections_in_bound && first_is_valid && second_is_valid
+    }
+}
+
+fn to_hailstones(input: &str) -> Result<Vec<Hailstone>, Error> {
+    let hailstone = map(
+        pair(terminated(triple(), tag(" @ ")), triple()),
+        |(pos, vel)| Hailstone { pos, vel },
+    );
+    Ok(parse(input, lines(hailstone))?)
+}
+
+/// How many pairs of hailstones' paths cross inside the test area.
+fn crossings(hailstores: Vec<Hailstone>, lower_bound: f64, upper_bound: f64) -> usize {
+    hailstores
+        .iter()
+        .combinations(2)
+        .filter(|c| {
+            let first = c[0];
+            let second = c[1];
+            first.path_intersects_future(second, lower_bound, upper_bound)
+        })
+        .count()
+}
+
+pub fn part1(input: &str, lower_bound: f64, upper_bound: f64) -> Result<usize, Error> {
+    Ok(crossings(to_hailstones(input)?, lower_bound, upper_bound))
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 24;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "lower-bound",
+            default: "200000000000000",
+            about: "the smallest x and y of the test area",
+        },
+        Param {
+            name: "upper-bound",
+            default: "400000000000000",
+            about: "the largest x and y of the test area",
+        },
+    ];
+
+    type Input<'a> = Vec<Hailstone>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<Hailstone>, Error> {
+        to_hailstones(input)
+    }
+
+    fn solve(hailstones: Vec<Hailstone>, params: &Params) -> Result<usize, Error> {
+        Ok(crossings(
+            hailstones,
+            params.get("lower-bound")?,
+            params.get("upper-bound")?,
+        ))
+    }
+}
+
+const EXAMPLE: &str = "\
+19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE, 7.0, 27.0);
+        assert_eq!(result, Ok(2));
+    }
+}
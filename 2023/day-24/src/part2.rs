@@ -0,0 +1,134 @@
+use crate::{triple, Error};
+use aoc_common::{
+    parse::{lines, pair, parse, tag, terminated},
+    Params, Solution,
+};
+
+pub type Vector = [i128; 3];
+
+fn sub(a: Vector, b: Vector) -> Option<Vector> {
+    Some([
+        a[0].checked_sub(b[0])?,
+        a[1].checked_sub(b[1])?,
+        a[2].checked_sub(b[2])?,
+    ])
+}
+
+/// `a + b * scale`.
+fn add_scaled(a: Vector, b: Vector, scale: i128) -> Option<Vector> {
+    let term = |i: usize| a[i].checked_add(b[i].checked_mul(scale)?);
+    Some([term(0)?, term(1)?, term(2)?])
+}
+
+fn cross(a: Vector, b: Vector) -> Option<Vector> {
+    let term = |i: usize, j: usize| a[i].checked_mul(b[j])?.checked_sub(a[j].checked_mul(b[i])?);
+    Some([term(1, 2)?, term(2, 0)?, term(0, 1)?])
+}
+
+fn dot(a: Vector, b: Vector) -> Option<i128> {
+    a.iter()
+        .zip(b)
+        .try_fold(0i128, |sum, (x, y)| sum.checked_add(x.checked_mul(y)?))
+}
+
+/// `numerator / denominator`, if it's a whole number.
+fn divide(numerator: i128, denominator: i128) -> Option<i128> {
+    (numerator.checked_rem(denominator)? == 0).then(|| numerator / denominator)
+}
+
+/// Each hailstone's position and velocity.
+fn to_hailstones(input: &str) -> Result<Vec<(Vector, Vector)>, Error> {
+    let hailstone = pair(terminated(triple::<i64>(), tag(" @ ")), triple::<i64>());
+    Ok(parse(input, lines(hailstone))?
+        .into_iter()
+        .map(|(position, velocity)| (position.map(i128::from), velocity.map(i128::from)))
+        .collect())
+}
+
+/// The position and velocity of the rock that hits every hailstone, or
+/// `None` if there isn't one or it can't be worked out in an `i128`.
+fn throw(hailstones: &[(Vector, Vector)]) -> Option<(Vector, Vector)> {
+    let [(p0, v0), (p1, v1), (p2, v2), ..] = *hailstones else {
+        return None;
+    };
+    // Seen from the first hailstone, it stays at the origin, so the rock
+    // passes through the origin and hits each of the other two in the plane
+    // the origin makes with the path of the other one.
+    let (p1, v1) = (sub(p1, p0)?, sub(v1, v0)?);
+    let (p2, v2) = (sub(p2, p0)?, sub(v2, v0)?);
+    let normal1 = cross(p1, v1)?;
+    let normal2 = cross(p2, v2)?;
+    let t1 = divide(-dot(p1, normal2)?, dot(v1, normal2)?)?;
+    let t2 = divide(-dot(p2, normal1)?, dot(v2, normal1)?)?;
+    let hit1 = add_scaled(p1, v1, t1)?;
+    let hit2 = add_scaled(p2, v2, t2)?;
+    let elapsed = t2.checked_sub(t1)?;
+    let [dx, dy, dz] = sub(hit2, hit1)?;
+    let velocity = [
+        divide(dx, elapsed)?,
+        divide(dy, elapsed)?,
+        divide(dz, elapsed)?,
+    ];
+    let position = add_scaled(hit1, velocity, -t1)?;
+    let (position, velocity) = (add_scaled(position, p0, 1)?, add_scaled(velocity, v0, 1)?);
+    // Each hailstone meets the rock exactly when they head straight for
+    // each other.
+    for &(p, v) in hailstones {
+        if cross(sub(position, p)?, sub(velocity, v)?)? != [0; 3] {
+            return None;
+        }
+    }
+    Some((position, velocity))
+}
+
+pub fn part2(input: &str) -> Result<i64, Error> {
+    coordinate_sum(&to_hailstones(input)?)
+}
+
+/// The sum of the coordinates the rock is thrown from.
+fn coordinate_sum(hailstones: &[(Vector, Vector)]) -> Result<i64, Error> {
+    let (position, _) = throw(hailstones).ok_or(Error::NoThrow)?;
+    i64::try_from(position.iter().sum::<i128>()).map_err(|_| Error::NoThrow)
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 24;
+    const PART: u8 = 2;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Vec<(Vector, Vector)>;
+    type Output = i64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Vec<(Vector, Vector)>, Error> {
+        to_hailstones(input)
+    }
+
+    fn solve(hailstones: Vec<(Vector, Vector)>, _: &Params) -> Result<i64, Error> {
+        coordinate_sum(&hailstones)
+    }
+}
+
+const EXAMPLE: &str = "\
+19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(47));
+
+        let result = part2(&format!("{EXAMPLE}\n0, 0, 0 @ 1, 1, 1"));
+        assert_eq!(result, Err(Error::NoThrow));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::Error;
+use aoc_common::{
+    graph::{Graph, Interner},
+    parse::{lines, pair, parse, separated, spaces, tag, terminated, word},
+    Params, Solution,
+};
+
+fn create_graph(input: &str) -> Result<Graph<&str, ()>, Error> {
+    // By using graph-tool to visualize the graph, I was able
+    // to establish that the three edges to be cut are:
+    // bvc: rsm
+    // bkm: ldk
+    // zmq: pgh
+    let mut graph = Graph::undirected();
+    let mut names = Interner::new();
+    let connections = pair(terminated(word(), tag(": ")), separated(word(), spaces()));
+    for (name, adj_names) in parse(input, lines(connections))? {
+        let curr = names.intern(&mut graph, name, |&name| name);
+        for adj_name in adj_names {
+            let adj = names.intern(&mut graph, adj_name, |&name| name);
+            graph.add_edge(curr, adj, ());
+        }
+    }
+    Ok(graph)
+}
+
+fn cluster_sizes(graph: &Graph<&str, ()>) -> Result<(usize, usize), Error> {
+    match &graph.connected_components()[..] {
+        [cluster1, cluster2] => Ok((cluster1.len(), cluster2.len())),
+        clusters => Err(Error::NotTwoGroups(clusters.len())),
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    group_product(&create_graph(input)?)
+}
+
+/// The sizes of the two groups multiplied together.
+fn group_product(graph: &Graph<&str, ()>) -> Result<usize, Error> {
+    let (size1, size2) = cluster_sizes(graph)?;
+    Ok(size1 * size2)
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 25;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = Graph<&'a str, ()>;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Graph<&str, ()>, Error> {
+        create_graph(input)
+    }
+
+    fn solve(graph: Graph<&str, ()>, _: &Params) -> Result<usize, Error> {
+        group_product(&graph)
+    }
+}
+
+/// The puzzle's example, like the input, with the three wires to cut
+/// (hfx/pzl, bvb/cmg and nvd/jqt) already taken out.
+const EXAMPLE: &str = "\
+jqt: rhn xhk
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[rustfmt::skip]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(54));
+    }
+}
@@ -1,23 +0,0 @@
-fn part1(input: &str) -> usize {
-    todo!();
-}
-
-fn main() {
-    let input = include_str!("./input.txt");
-    let output = part1(input);
-    dbg!(output);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    #[rustfmt::skip]
-    fn part1_test() {
-        let result = part1("\
-TEST_CASE",
-        );
-        assert_eq!(result, TEST_RESULT);
-    }
-}
@@ -0,0 +1,149 @@
+//! Finding where a simulation starts repeating itself, so it can be skipped
+//! ahead by a billion steps without running them.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// How the states `start`, `step(start)`, `step(step(start))`, … repeat:
+/// after the first `prefix` of them, the same `period` states come round
+/// forever.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The step before `prefix + period` that leads to the same state as
+    /// step `n`.
+    pub fn equivalent_step(self, n: u64) -> usize {
+        let prefix = self.prefix as u64;
+        if n < prefix {
+            n as usize
+        } else {
+            self.prefix + ((n - prefix) % self.period as u64) as usize
+        }
+    }
+
+    /// The state `n` steps from `start`, taking fewer than `prefix + period`
+    /// steps to get there.
+    pub fn nth_state<S>(self, start: S, mut step: impl FnMut(&S) -> S, n: u64) -> S {
+        (0..self.equivalent_step(n)).fold(start, |state, _| step(&state))
+    }
+}
+
+/// Finds the cycle by remembering every state until one comes round again.
+pub fn find_cycle<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S) -> Cycle {
+    find_cycle_by_key(start, step, S::clone)
+}
+
+/// Finds the cycle by remembering each state's `key` until one comes round
+/// again. Keys must be equal exactly when the states are, or at least when
+/// the parts of them the puzzle cares about are.
+pub fn find_cycle_by_key<S, K: Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    let mut index = 0;
+    loop {
+        match seen.entry(key(&state)) {
+            Entry::Occupied(first) => {
+                return Cycle {
+                    prefix: *first.get(),
+                    period: index - first.get(),
+                }
+            }
+            Entry::Vacant(first) => {
+                first.insert(index);
+            }
+        }
+        state = step(&state);
+        index += 1;
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only ever keeps two states
+/// but runs the simulation a few times over.
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The tortoise waits at each power of two for the hare to lap it.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+    // With the hare a whole period ahead, they meet where the loop starts.
+    let mut hare = (0..period).fold(start.clone(), |state, _| step(&state));
+    let mut tortoise = start;
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn cycle_test() {
+        // 0, 1, 2, 3, 4, 5, 3, 4, 5, …
+        let step = |&n: &u32| if n == 5 { 3 } else { n + 1 };
+        let cycle = Cycle {
+            prefix: 3,
+            period: 3,
+        };
+        assert_eq!(find_cycle(0, step), cycle);
+        assert_eq!(brent(0, step), cycle);
+        assert_eq!(
+            find_cycle_by_key(0u32, |n| n + 1, |n| n % 4),
+            Cycle {
+                prefix: 0,
+                period: 4
+            }
+        );
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 4);
+        assert_eq!(cycle.nth_state(0, step, 1_000_000_000), 4);
+        assert_eq!(
+            find_cycle(7, |&n: &u32| n),
+            Cycle {
+                prefix: 0,
+                period: 1
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn brent_matches_hashing(
+            table in prop::collection::vec(0..20usize, 20),
+            start in 0..20usize,
+            n in 0..1u64 << 62,
+        ) {
+            let step = |&state: &usize| table[state];
+            let cycle = find_cycle(start, step);
+            prop_assert_eq!(brent(start, step), cycle);
+            let walked = (0..cycle.prefix + cycle.period).fold(start, |state, _| step(&state));
+            prop_assert_eq!(walked, cycle.nth_state(start, step, cycle.prefix as u64));
+            let skipped = cycle.nth_state(start, step, n);
+            prop_assert_eq!(step(&skipped), cycle.nth_state(start, step, n + 1));
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+
+/// One of the four orthogonal directions, with up towards row 0.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The `(row, col)` offset of one step this way.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Both directions at a right angle to this one, left first.
+    pub fn turns(self) -> [Self; 2] {
+        [self.turn_left(), self.turn_right()]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// `U`, `R`, `D` or `L`.
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'U' => Some(Direction::Up),
+            'R' => Some(Direction::Right),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn letter(self) -> char {
+        match self {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        }
+    }
+
+    /// `0` to `3` for right, down, left and up, the way 2023 day 18's colour
+    /// codes put it.
+    pub fn from_hex_digit(digit: char) -> Option<Self> {
+        match digit {
+            '0' => Some(Direction::Right),
+            '1' => Some(Direction::Down),
+            '2' => Some(Direction::Left),
+            '3' => Some(Direction::Up),
+            _ => None,
+        }
+    }
+
+    pub fn hex_digit(self) -> char {
+        match self {
+            Direction::Right => '0',
+            Direction::Down => '1',
+            Direction::Left => '2',
+            Direction::Up => '3',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(letter: char) -> Result<Self, String> {
+        Direction::from_letter(letter).ok_or_else(|| format!("{letter:?} isn't U, R, D or L"))
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+/// A tile of a grid, as `(row, col)` from the top left.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Position { row, col }
+    }
+
+    /// One step `direction`, unless that goes above row 0 or left of
+    /// column 0.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (row_offset, col_offset) = direction.offset();
+        Some(Position {
+            row: self.row.checked_add_signed(row_offset)?,
+            col: self.col.checked_add_signed(col_offset)?,
+        })
+    }
+
+    /// One step `direction`, unless that leaves a `width` by `height` grid.
+    pub fn step_within(self, direction: Direction, width: usize, height: usize) -> Option<Self> {
+        self.step(direction)
+            .filter(|next| next.row < height && next.col < width)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((row, col): (usize, usize)) -> Self {
+        Position { row, col }
+    }
+}
+
+impl From<Position> for (usize, usize) {
+    fn from(position: Position) -> Self {
+        (position.row, position.col)
+    }
+}
+
+/// A point on an unbounded plane, for walks that aren't confined to a grid.
+/// Rows and columns grow the same way as a `Position`'s.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    /// The point `distance` steps `direction` from this one.
+    pub fn step(self, direction: Direction, distance: isize) -> Self {
+        let (row_offset, col_offset) = direction.offset();
+        Point {
+            row: self.row + row_offset * distance,
+            col: self.col + col_offset * distance,
+        }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        Point {
+            row: position.row as isize,
+            col: position.col as isize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geometry_test() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(Direction::from_letter(direction.letter()), Some(direction));
+            assert_eq!(
+                Direction::from_hex_digit(direction.hex_digit()),
+                Some(direction)
+            );
+            let (row, col) = direction.offset();
+            let (opposite_row, opposite_col) = direction.opposite().offset();
+            assert_eq!((row + opposite_row, col + opposite_col), (0, 0));
+        }
+        assert_eq!(Direction::Up.turns(), [Direction::Left, Direction::Right]);
+        assert!(Direction::Down.is_vertical());
+        assert_eq!(
+            Direction::try_from('x').unwrap_err(),
+            "'x' isn't U, R, D or L"
+        );
+
+        let corner = Position::new(0, 2);
+        assert_eq!(corner.step(Direction::Up), None);
+        assert_eq!(corner.step(Direction::Right), Some(Position::new(0, 3)));
+        assert_eq!(corner.step_within(Direction::Right, 3, 3), None);
+        assert_eq!(
+            corner.step_within(Direction::Down, 3, 3),
+            Some(Position::new(1, 2))
+        );
+        assert_eq!(corner.manhattan_distance(Position::new(3, 0)), 5);
+
+        let point = Point::from(corner).step(Direction::Up, 4);
+        assert_eq!(point, Point::new(-4, 2));
+        assert_eq!(point.manhattan_distance(Point::default()), 6);
+    }
+}
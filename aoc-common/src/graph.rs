@@ -0,0 +1,569 @@
+//! Graphs kept in vectors and addressed by index, so puzzles can build
+//! cyclic and shared structures without `Rc<RefCell<…>>`.
+
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    hash::Hash,
+    ops::{Index, IndexMut},
+};
+
+/// A node of a `Graph`. Ids are handed out in order from 0 and stay valid
+/// for the life of the graph.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// An edge of a `Graph`. Removing an edge doesn't renumber the others.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct EdgeId(usize);
+
+impl EdgeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Edge<E> {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub weight: E,
+}
+
+impl<E> Edge<E> {
+    /// The end of the edge that isn't `node`.
+    pub fn other(&self, node: NodeId) -> NodeId {
+        if self.from == node {
+            self.to
+        } else {
+            self.from
+        }
+    }
+}
+
+/// Nodes carrying an `N` joined by edges carrying an `E`, either directed
+/// or undirected. In an undirected graph every edge goes both ways, so a
+/// node's successors and predecessors are the same.
+#[derive(Clone, Debug)]
+pub struct Graph<N, E> {
+    directed: bool,
+    nodes: Vec<N>,
+    edges: Vec<Option<Edge<E>>>,
+    outgoing: Vec<Vec<EdgeId>>,
+    incoming: Vec<Vec<EdgeId>>,
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Graph {
+            directed,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        self.nodes.push(node);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: E) -> EdgeId {
+        let id = EdgeId(self.edges.len());
+        self.edges.push(Some(Edge { from, to, weight }));
+        self.outgoing[from.0].push(id);
+        if self.directed {
+            self.incoming[to.0].push(id);
+        } else {
+            self.outgoing[to.0].push(id);
+        }
+        id
+    }
+
+    /// Takes an edge out of the graph, giving it back if it was still there.
+    pub fn remove_edge(&mut self, id: EdgeId) -> Option<Edge<E>> {
+        let edge = self.edges.get_mut(id.0)?.take()?;
+        self.outgoing[edge.from.0].retain(|&other| other != id);
+        let to_list = if self.directed {
+            &mut self.incoming[edge.to.0]
+        } else {
+            &mut self.outgoing[edge.to.0]
+        };
+        to_list.retain(|&other| other != id);
+        Some(edge)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().flatten().count()
+    }
+
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    pub fn edge(&self, id: EdgeId) -> Option<&Edge<E>> {
+        self.edges.get(id.0)?.as_ref()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (EdgeId, &Edge<E>)> {
+        self.edges
+            .iter()
+            .enumerate()
+            .filter_map(|(id, edge)| Some((EdgeId(id), edge.as_ref()?)))
+    }
+
+    /// The ids of the edges leaving `node`, in the order they were added.
+    pub fn edge_ids_from(&self, node: NodeId) -> &[EdgeId] {
+        &self.outgoing[node.0]
+    }
+
+    /// The ids of the edges arriving at `node`, in the order they were added.
+    pub fn edge_ids_to(&self, node: NodeId) -> &[EdgeId] {
+        if self.directed {
+            &self.incoming[node.0]
+        } else {
+            &self.outgoing[node.0]
+        }
+    }
+
+    /// Where each edge leaving `node` goes, with its weight.
+    pub fn edges_from(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> {
+        self.edge_ids_from(node).iter().map(move |&id| {
+            let edge = self.edges[id.0].as_ref().unwrap();
+            (edge.other(node), &edge.weight)
+        })
+    }
+
+    /// Where each edge arriving at `node` comes from, with its weight.
+    pub fn edges_to(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> {
+        self.edge_ids_to(node).iter().map(move |&id| {
+            let edge = self.edges[id.0].as_ref().unwrap();
+            (edge.other(node), &edge.weight)
+        })
+    }
+
+    pub fn successors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges_from(node).map(|(next, _)| next)
+    }
+
+    pub fn predecessors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges_to(node).map(|(prev, _)| prev)
+    }
+
+    pub fn out_degree(&self, node: NodeId) -> usize {
+        self.edge_ids_from(node).len()
+    }
+
+    pub fn in_degree(&self, node: NodeId) -> usize {
+        self.edge_ids_to(node).len()
+    }
+
+    /// Every node, ordered so that each edge goes from an earlier node to a
+    /// later one, or `None` if there's a cycle. Uses Kahn's algorithm.
+    pub fn topological_sort(&self) -> Option<Vec<NodeId>> {
+        let mut incoming: Vec<usize> = self.node_ids().map(|id| self.in_degree(id)).collect();
+        let mut no_incoming: Vec<NodeId> =
+            self.node_ids().filter(|id| incoming[id.0] == 0).collect();
+        let mut sorted = Vec::with_capacity(self.nodes.len());
+        while let Some(node) = no_incoming.pop() {
+            sorted.push(node);
+            for next in self.successors(node) {
+                incoming[next.0] -= 1;
+                if incoming[next.0] == 0 {
+                    no_incoming.push(next);
+                }
+            }
+        }
+        (sorted.len() == self.nodes.len()).then_some(sorted)
+    }
+
+    /// The strongly connected components, each a set of nodes that can all
+    /// reach each other. A component comes after every component it has
+    /// edges into, so the order is a reverse topological sort of them. Uses
+    /// Tarjan's algorithm.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        const UNVISITED: usize = usize::MAX;
+        let mut index = vec![UNVISITED; self.nodes.len()];
+        let mut low_link = vec![0; self.nodes.len()];
+        let mut on_stack = vec![false; self.nodes.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+        for root in self.node_ids() {
+            if index[root.0] != UNVISITED {
+                continue;
+            }
+            // Each frame is a node and how many of its edges it has tried.
+            let mut frames = vec![(root, 0)];
+            index[root.0] = next_index;
+            low_link[root.0] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root.0] = true;
+            while let Some(&mut (node, ref mut tried)) = frames.last_mut() {
+                if let Some(&edge) = self.outgoing[node.0].get(*tried) {
+                    *tried += 1;
+                    let next = self.edges[edge.0].as_ref().unwrap().other(node);
+                    if index[next.0] == UNVISITED {
+                        index[next.0] = next_index;
+                        low_link[next.0] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next.0] = true;
+                        frames.push((next, 0));
+                    } else if on_stack[next.0] {
+                        low_link[node.0] = low_link[node.0].min(index[next.0]);
+                    }
+                    continue;
+                }
+                frames.pop();
+                if let Some(&(parent, _)) = frames.last() {
+                    low_link[parent.0] = low_link[parent.0].min(low_link[node.0]);
+                }
+                if low_link[node.0] == index[node.0] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member.0] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// The sets of nodes joined by edges, whichever way the edges go.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut components = Vec::new();
+        for root in self.node_ids() {
+            if seen[root.0] {
+                continue;
+            }
+            seen[root.0] = true;
+            let mut component = vec![root];
+            let mut unexplored = vec![root];
+            while let Some(node) = unexplored.pop() {
+                for next in self.successors(node).chain(self.predecessors(node)) {
+                    if !seen[next.0] {
+                        seen[next.0] = true;
+                        component.push(next);
+                        unexplored.push(next);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// Moves every edge of `gone` over to `keep`, leaving `gone` in the
+    /// graph with no edges. Edges between the two would become loops and are
+    /// dropped. Where a moved edge joins the same nodes as one `keep` already
+    /// has, the same way if directed, the two become one weighted by `merge`
+    /// of the existing weight and the moved one.
+    pub fn merge_nodes(&mut self, keep: NodeId, gone: NodeId, mut merge: impl FnMut(E, E) -> E) {
+        if keep == gone {
+            return;
+        }
+        let mut ids = self.outgoing[gone.0].clone();
+        if self.directed {
+            ids.extend_from_slice(&self.incoming[gone.0]);
+        }
+        for id in ids {
+            // A loop is listed twice, so it may be gone already.
+            let Some(edge) = self.remove_edge(id) else {
+                continue;
+            };
+            if edge.from == keep || edge.to == keep {
+                continue;
+            }
+            let moved = |node| if node == gone { keep } else { node };
+            self.add_or_merge_edge(moved(edge.from), moved(edge.to), edge.weight, &mut merge);
+        }
+    }
+
+    /// Contracts an edge, merging its `to` end into its `from` end with
+    /// `merge_nodes`. Gives the edge back, or `None` if it was already gone.
+    pub fn contract_edge(&mut self, id: EdgeId, merge: impl FnMut(E, E) -> E) -> Option<Edge<E>> {
+        let edge = self.remove_edge(id)?;
+        self.merge_nodes(edge.from, edge.to, merge);
+        Some(edge)
+    }
+
+    /// Contracts away a node that only passes through: one with exactly one
+    /// edge in and one edge out, or exactly two edges if undirected, to two
+    /// different nodes. Both edges become one joining its neighbors,
+    /// weighted by `join` of the incoming weight and the outgoing one, and
+    /// merged with `merge` into any edge the neighbors already share. The
+    /// node is left in the graph with no edges. Gives the joining edge, or
+    /// `None` without changing anything if `node` isn't like that.
+    pub fn smooth(
+        &mut self,
+        node: NodeId,
+        join: impl FnOnce(E, E) -> E,
+        mut merge: impl FnMut(E, E) -> E,
+    ) -> Option<EdgeId> {
+        let (incoming, outgoing) = if self.directed {
+            match (&self.incoming[node.0][..], &self.outgoing[node.0][..]) {
+                (&[incoming], &[outgoing]) => (incoming, outgoing),
+                _ => return None,
+            }
+        } else {
+            match self.outgoing[node.0][..] {
+                [incoming, outgoing] => (incoming, outgoing),
+                _ => return None,
+            }
+        };
+        let from = self.edge(incoming)?.other(node);
+        let to = self.edge(outgoing)?.other(node);
+        if from == node || to == node || from == to {
+            return None;
+        }
+        // Fold the incoming weight into the outgoing edge, then contract the
+        // node into where it's entered from.
+        let incoming = self.remove_edge(incoming)?;
+        let outgoing = self.remove_edge(outgoing)?;
+        self.add_edge(node, to, join(incoming.weight, outgoing.weight));
+        self.merge_nodes(from, node, &mut merge);
+        self.edge_ids_from(from)
+            .iter()
+            .copied()
+            .find(|&id| self.edges[id.0].as_ref().unwrap().other(from) == to)
+    }
+
+    /// Adds an edge, or merges it into the edge already joining `from` to
+    /// `to`.
+    fn add_or_merge_edge(
+        &mut self,
+        from: NodeId,
+        to: NodeId,
+        weight: E,
+        merge: &mut impl FnMut(E, E) -> E,
+    ) -> EdgeId {
+        let existing = self.outgoing[from.0]
+            .iter()
+            .copied()
+            .find(|&id| self.edges[id.0].as_ref().unwrap().other(from) == to);
+        match existing.and_then(|id| self.remove_edge(id)) {
+            Some(existing) => self.add_edge(from, to, merge(existing.weight, weight)),
+            None => self.add_edge(from, to, weight),
+        }
+    }
+}
+
+impl<N, E> Index<NodeId> for Graph<N, E> {
+    type Output = N;
+
+    fn index(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+}
+
+impl<N, E> IndexMut<NodeId> for Graph<N, E> {
+    fn index_mut(&mut self, id: NodeId) -> &mut N {
+        &mut self.nodes[id.0]
+    }
+}
+
+/// Gives each distinct key, like a module's name, its own node.
+#[derive(Clone, Debug)]
+pub struct Interner<K> {
+    ids: HashMap<K, NodeId>,
+}
+
+impl<K: Eq + Hash> Default for Interner<K> {
+    fn default() -> Self {
+        Interner {
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> Interner<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The node already interned for `key`.
+    pub fn id<Q>(&self, key: &Q) -> Option<NodeId>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(key).copied()
+    }
+
+    /// The node for `key`, adding `node(&key)` to `graph` the first time.
+    pub fn intern<N, E>(
+        &mut self,
+        graph: &mut Graph<N, E>,
+        key: K,
+        node: impl FnOnce(&K) -> N,
+    ) -> NodeId {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = graph.add_node(node(&key));
+        self.ids.insert(key, id);
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph_test() {
+        let mut names = Interner::new();
+        let mut graph: Graph<&str, u32> = Graph::directed();
+        for (from, to) in [("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("e", "d")] {
+            let from = names.intern(&mut graph, from, |&name| name);
+            let to = names.intern(&mut graph, to, |&name| name);
+            graph.add_edge(from, to, 1);
+        }
+        let id = |name: &str| names.id(name).unwrap();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph[id("e")], "e");
+        assert_eq!(
+            graph.successors(id("c")).collect::<Vec<_>>(),
+            [id("a"), id("d")]
+        );
+        assert_eq!(
+            graph.predecessors(id("d")).collect::<Vec<_>>(),
+            [id("c"), id("e")]
+        );
+        assert_eq!(graph.topological_sort(), None);
+
+        let mut components = graph.strongly_connected_components();
+        components.iter_mut().for_each(|component| component.sort());
+        assert_eq!(
+            components,
+            [
+                vec![id("d")],
+                vec![id("a"), id("b"), id("c")],
+                vec![id("e")]
+            ]
+        );
+        assert_eq!(graph.connected_components().len(), 1);
+
+        let back_edge = graph.edge_ids_from(id("c"))[0];
+        assert_eq!(
+            graph.remove_edge(back_edge).map(|edge| edge.to),
+            Some(id("a"))
+        );
+        assert_eq!(graph.remove_edge(back_edge), None);
+        let sorted = graph.topological_sort().unwrap();
+        for (_, edge) in graph.edges() {
+            let position = |node| sorted.iter().position(|&other| other == node);
+            assert!(position(edge.from) < position(edge.to));
+        }
+
+        // b only passes a on to c.
+        let smoothed = graph.smooth(id("b"), |a, b| a + b, u32::max).unwrap();
+        assert_eq!(graph.edge(smoothed).map(|edge| edge.weight), Some(2));
+        assert_eq!(graph.successors(id("a")).collect::<Vec<_>>(), [id("c")]);
+        assert_eq!(graph.out_degree(id("b")) + graph.in_degree(id("b")), 0);
+        assert_eq!(graph.smooth(id("d"), |a, b| a + b, u32::max), None);
+        assert_eq!(graph.connected_components().len(), 2);
+
+        let mut path = Graph::undirected();
+        let nodes: Vec<NodeId> = (0..4).map(|n| path.add_node(n)).collect();
+        for pair in nodes.windows(2) {
+            path.add_edge(pair[0], pair[1], 1);
+        }
+        assert_eq!(
+            path.successors(nodes[1]).collect::<Vec<_>>(),
+            [nodes[0], nodes[2]]
+        );
+        assert_eq!(path.smooth(nodes[0], |a, b| a + b, u32::max), None);
+        path.smooth(nodes[1], |a, b| a + b, u32::max);
+        path.smooth(nodes[2], |a, b| a + b, u32::max);
+        assert_eq!(
+            path.edges_from(nodes[0]).collect::<Vec<_>>(),
+            [(nodes[3], &3)]
+        );
+        assert_eq!(path.edge_count(), 1);
+
+        // a-b is contracted, so b-c lands on a-c and the two add up.
+        let mut triangle = Graph::undirected();
+        let [a, b, c, d] = [0, 1, 2, 3].map(|n| triangle.add_node(n));
+        let contracted = triangle.add_edge(a, b, 1);
+        triangle.add_edge(b, c, 2);
+        triangle.add_edge(c, a, 4);
+        triangle.add_edge(c, d, 8);
+        let edge = triangle.contract_edge(contracted, |x, y| x + y).unwrap();
+        assert_eq!((edge.from, edge.to, edge.weight), (a, b, 1));
+        assert_eq!(triangle.edges_from(a).collect::<Vec<_>>(), [(c, &6)]);
+        assert_eq!(triangle.out_degree(b), 0);
+        assert_eq!(triangle.edge_count(), 2);
+        assert!(triangle.contract_edge(contracted, |x, y| x + y).is_none());
+
+        // Directed edges keep their way round, and only the same way merge.
+        let mut directed = Graph::directed();
+        let [x, y, z] = [0, 1, 2].map(|n| directed.add_node(n));
+        let contracted = directed.add_edge(x, y, 1);
+        directed.add_edge(y, z, 2);
+        directed.add_edge(x, z, 3);
+        directed.add_edge(z, y, 5);
+        directed.contract_edge(contracted, u32::min);
+        assert_eq!(directed.edges_from(x).collect::<Vec<_>>(), [(z, &2)]);
+        assert_eq!(directed.edges_to(x).collect::<Vec<_>>(), [(z, &5)]);
+        assert_eq!(directed.in_degree(y) + directed.out_degree(y), 0);
+
+        // Edges between merged nodes are dropped, but a loop moves over.
+        let mut parallel = Graph::undirected();
+        let [p, q] = [0, 1].map(|n| parallel.add_node(n));
+        parallel.add_edge(p, q, 1);
+        parallel.add_edge(q, p, 1);
+        parallel.add_edge(q, q, 7);
+        parallel.merge_nodes(p, q, |x, y| x + y);
+        assert_eq!(parallel.edge_count(), 1);
+        assert_eq!(parallel.successors(p).collect::<Vec<_>>(), [p, p]);
+
+        // Smoothing merges into an edge the neighbours already share.
+        for (merge, expected) in [(u32::max as fn(u32, u32) -> u32, 10), (u32::min, 3)] {
+            let mut bypass = Graph::undirected();
+            let [a, m, b] = [0, 1, 2].map(|n| bypass.add_node(n));
+            bypass.add_edge(a, m, 1);
+            bypass.add_edge(m, b, 2);
+            bypass.add_edge(a, b, 10);
+            let smoothed = bypass.smooth(m, |x, y| x + y, merge).unwrap();
+            assert_eq!(
+                bypass.edge(smoothed).map(|edge| edge.weight),
+                Some(expected)
+            );
+            assert_eq!(bypass.edge_count(), 1);
+        }
+    }
+}
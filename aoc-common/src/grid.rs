@@ -0,0 +1,406 @@
+use crate::geometry::{Direction, Position};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A rectangular grid of tiles stored row by row. Positions are
+/// `(row, col)`, with `(0, 0)` at the top left.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every tile set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its tiles in row order.
+    ///
+    /// Panics if `cells` doesn't fill whole rows of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} tiles don't make rows of {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, mapping each character to a tile.
+    ///
+    /// Panics if the lines aren't all the same length.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> T) -> Self {
+        Self::try_parse(input, |c| Ok::<_, String>(tile(c))).unwrap()
+    }
+
+    /// Like `parse`, but a tile can be refused. The error says where.
+    pub fn try_parse<E: fmt::Display>(
+        input: &str,
+        mut tile: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, String> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                cells.push(tile(c).map_err(|err| format!("{}:{}: {err}", row + 1, col + 1))?);
+            }
+            let line_width = cells.len() - before;
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(format!(
+                        "{}: row is {line_width} wide, expected {width}",
+                        row + 1
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Self::from_cells(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// One step `direction` from `position`, if that is still inside.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step_within(direction, self.width, self.height)
+    }
+
+    /// Maps a position on the grid repeated infinitely in every direction
+    /// back onto the grid.
+    pub fn wrap(&self, (row, col): (isize, isize)) -> (usize, usize) {
+        (
+            row.rem_euclid(self.height as isize) as usize,
+            col.rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    /// The orthogonal neighbours of `position` that are inside the grid,
+    /// clockwise from up.
+    pub fn neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of `position` that are inside
+    /// the grid, clockwise from up.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL.into_iter().flat_map(move |direction| {
+            let orthogonal = self.step(position, direction);
+            // A diagonal neighbour inside the grid always has this one
+            // inside too, so the second step finds it.
+            let diagonal =
+                orthogonal.and_then(|orthogonal| self.step(orthogonal, direction.turn_right()));
+            [orthogonal, diagonal].into_iter().flatten()
+        })
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every tile with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first tile, row by row, that matches.
+    pub fn find(&self, mut matches: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find_map(|(position, tile)| matches(tile).then_some(position))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The tiles of one column, top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(
+            col < self.width,
+            "column {col} is outside a grid {} wide",
+            self.width
+        );
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        assert!(self.contains(a) && self.contains(b));
+        self.cells
+            .swap(a.0 * self.width + a.1, b.0 * self.width + b.1);
+    }
+
+    pub fn map<U>(&self, tile: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(tile).collect(),
+        }
+    }
+
+    /// Builds a grid of the given size by looking up where each of its
+    /// tiles comes from in this one.
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| self[source(row, col)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Rows become columns: the tile at `(row, col)` moves to `(col, row)`.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |row, col| (col, row))
+    }
+
+    /// Turns the grid a quarter turn clockwise, so the left column becomes
+    /// the top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(self.height, self.width, |row, col| (height - 1 - col, row))
+    }
+
+    /// Turns the grid a quarter turn counterclockwise, so the right column
+    /// becomes the top row.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.height, self.width, |row, col| (col, width - 1 - row))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.width, self.height, |row, col| (row, width - 1 - col))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(self.width, self.height, |row, col| (height - 1 - row, col))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{position:?} is outside a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        &self[(position.row, position.col)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        &mut self[(position.row, position.col)]
+    }
+}
+
+/// Parses each character with the tile type's `TryFrom<char>`.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, String> {
+        Self::try_parse(input, T::try_from)
+    }
+}
+
+/// Prints one row per line, each tile as the character it converts to.
+impl<T> fmt::Display for Grid<T>
+where
+    T: Copy + Into<char>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for &tile in row {
+                write!(f, "{}", tile.into())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_test() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.find(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.wrap((-1, 4)), (1, 1));
+        assert_eq!(
+            grid.neighbors(Position::new(0, 0)).collect::<Vec<_>>(),
+            [Position::new(0, 1), Position::new(1, 0)]
+        );
+        assert_eq!(grid.neighbors(Position::new(1, 1)).count(), 3);
+        assert_eq!(
+            grid.neighbors8(Position::new(0, 0)).collect::<Vec<_>>(),
+            [
+                Position::new(0, 1),
+                Position::new(1, 1),
+                Position::new(1, 0)
+            ]
+        );
+        assert_eq!(
+            grid.neighbors8(Position::new(1, 1)).collect::<Vec<_>>(),
+            [
+                Position::new(0, 1),
+                Position::new(0, 2),
+                Position::new(1, 2),
+                Position::new(1, 0),
+                Position::new(0, 0),
+            ]
+        );
+        assert_eq!(grid.neighbors8(Position::new(0, 2)).count(), 3);
+        let wide: Grid<char> = "abc
+def
+ghi"
+        .parse()
+        .unwrap();
+        assert_eq!(wide.neighbors8(Position::new(1, 1)).count(), 8);
+        assert_eq!(grid.step(Position::new(1, 1), Direction::Down), None);
+        assert_eq!(
+            grid[grid.step(Position::new(1, 1), Direction::Left).unwrap()],
+            'd'
+        );
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        let turned = (0..4).fold(grid.clone(), |grid, _| grid.rotate_clockwise());
+        assert_eq!(turned, grid);
+
+        let digits = Grid::try_parse("12\n3x", |c| c.to_digit(10).ok_or("not a digit"));
+        assert_eq!(digits.unwrap_err(), "2:2: not a digit");
+        let ragged = "ab\nc".parse::<Grid<char>>();
+        assert_eq!(ragged.unwrap_err(), "2: row is 1 wide, expected 2");
+
+        let mut grid = grid.map(|&c| c == 'a');
+        grid.swap((0, 0), (1, 2));
+        grid[(0, 1)] = true;
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, &tile)| tile)
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>(),
+            [(0, 1), (1, 2)]
+        );
+    }
+}
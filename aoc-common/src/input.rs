@@ -0,0 +1,125 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Overrides where inputs are looked up. When set, day NN of year YYYY reads
+/// `$AOC_INPUT_DIR/YYYY/day-NN.txt` instead of its own `src/bin/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` reads from stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn in_dir(dir: &Path, year: u16, day: u8) -> Self {
+        InputSource::File(dir.join(year.to_string()).join(format!("day-{day:02}.txt")))
+    }
+
+    /// The input of the day crate at `YYYY/day-NN`, honoring `AOC_INPUT_DIR`.
+    pub fn default_for(day_dir: &Path) -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => {
+                let day_name = day_dir.file_name().unwrap_or_default();
+                let year = day_dir
+                    .parent()
+                    .and_then(Path::file_name)
+                    .unwrap_or_default();
+                let mut file_name = day_name.to_owned();
+                file_name.push(".txt");
+                InputSource::File(PathBuf::from(dir).join(year).join(file_name))
+            }
+            None => InputSource::File(day_dir.join("src/bin/input.txt")),
+        }
+    }
+
+    /// The first command line argument if there is one, otherwise the default
+    /// for the day crate at `day_dir`.
+    pub fn from_args(day_dir: &Path) -> Self {
+        match env::args().nth(1) {
+            Some(arg) => InputSource::from_arg(&arg),
+            None => InputSource::default_for(day_dir),
+        }
+    }
+
+    /// Reads the whole input, dropping a single trailing newline the same way
+    /// downloaded inputs are saved.
+    pub fn read(&self) -> io::Result<String> {
+        let mut input = match self {
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+        };
+        if input.ends_with('\n') {
+            input.pop();
+        }
+        Ok(input)
+    }
+}
+
+/// Loads the puzzle input for the calling day's binary: the path given as the
+/// first argument (`-` for stdin), otherwise `$AOC_INPUT_DIR/YYYY/day-NN.txt`,
+/// otherwise the day's `src/bin/input.txt`.
+#[cfg(not(feature = "embed-input"))]
+#[macro_export]
+macro_rules! input {
+    () => {
+        $crate::input::InputSource::from_args(::std::path::Path::new(env!("CARGO_MANIFEST_DIR")))
+            .read()
+    };
+}
+
+/// Like the default `input!`, but falls back to the `src/bin/input.txt` that
+/// was compiled into the binary instead of reading it at runtime.
+#[cfg(feature = "embed-input")]
+#[macro_export]
+macro_rules! input {
+    () => {
+        match ::std::env::args().nth(1) {
+            Some(arg) => $crate::input::InputSource::from_arg(&arg).read(),
+            None => ::std::io::Result::Ok(
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin/input.txt")).to_owned(),
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_source_test() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("inputs/day-07.txt"),
+            InputSource::File(PathBuf::from("inputs/day-07.txt"))
+        );
+        assert_eq!(
+            InputSource::in_dir(Path::new("inputs"), 2023, 7),
+            InputSource::File(PathBuf::from("inputs/2023/day-07.txt"))
+        );
+
+        let path = env::temp_dir().join(format!("aoc-input-test-{}.txt", std::process::id()));
+        fs::write(&path, "1abc2\ntreb7uchet\n").unwrap();
+        let read = InputSource::File(path.clone()).read();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(read.unwrap(), "1abc2\ntreb7uchet");
+    }
+}
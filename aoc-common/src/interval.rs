@@ -0,0 +1,472 @@
+//! Ranges of integers, sets of them and boxes made of them, for puzzles
+//! that push whole ranges of values through rules instead of one value at a
+//! time.
+
+use std::ops::{Range, RangeInclusive};
+
+/// The integers from `start` up to but not including `end`. Every
+/// empty interval is the same, whatever it was made from.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    pub const EMPTY: Interval = Interval { start: 0, end: 0 };
+
+    /// `start..end`, leaving out `end`.
+    pub fn new(start: i64, end: i64) -> Self {
+        if start < end {
+            Interval { start, end }
+        } else {
+            Self::EMPTY
+        }
+    }
+
+    /// `first..=last`, taking in `last`.
+    pub fn inclusive(first: i64, last: i64) -> Self {
+        Self::new(first, last.saturating_add(1))
+    }
+
+    /// `len` integers from `start`.
+    pub fn with_len(start: i64, len: u64) -> Self {
+        Self::new(start, start.saturating_add_unsigned(len))
+    }
+
+    pub fn start(self) -> i64 {
+        self.start
+    }
+
+    /// The first integer past the interval.
+    pub fn end(self) -> i64 {
+        self.end
+    }
+
+    /// The last integer in the interval, if there is one.
+    pub fn last(self) -> Option<i64> {
+        (!self.is_empty()).then(|| self.end - 1)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(self) -> u64 {
+        self.end.abs_diff(self.start)
+    }
+
+    pub fn contains(self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(self, other: Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The parts of this interval before and after `other`.
+    pub fn difference(self, other: Self) -> (Self, Self) {
+        if other.is_empty() {
+            return (self, Self::EMPTY);
+        }
+        (
+            Self::new(self.start, self.end.min(other.start)),
+            Self::new(self.start.max(other.end), self.end),
+        )
+    }
+
+    /// The parts below `pivot` and from `pivot` on.
+    pub fn split_at(self, pivot: i64) -> (Self, Self) {
+        (
+            Self::new(self.start, self.end.min(pivot)),
+            Self::new(self.start.max(pivot), self.end),
+        )
+    }
+
+    /// Every integer in the interval plus `offset`.
+    pub fn offset(self, offset: i64) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(self.start + offset, self.end + offset)
+    }
+
+    pub fn iter(self) -> Range<i64> {
+        self.start..self.end
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl From<RangeInclusive<i64>> for Interval {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Self::inclusive(*range.start(), *range.end())
+    }
+}
+
+/// Any set of integers, kept as the fewest intervals that cover it, in
+/// order.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer in `interval`, merging it with the intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // The first interval that ends at or after this one starts, and the
+        // first that starts after it ends: everything between merges.
+        let from = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let to = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+        let merged = self.intervals[from..to]
+            .iter()
+            .fold(interval, |merged, other| Interval {
+                start: merged.start.min(other.start),
+                end: merged.end.max(other.end),
+            });
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(|interval| interval.len()).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        Some(self.intervals.first()?.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last()?.last()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for interval in other.iter() {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&a), Some(&b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let overlap = a.intersection(b);
+            if !overlap.is_empty() {
+                intersection.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet {
+            intervals: intersection,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Vec::new();
+        let mut j = 0;
+        for &interval in &self.intervals {
+            let mut start = interval.start;
+            while let Some(&cut) = other.intervals.get(j) {
+                if cut.start >= interval.end {
+                    break;
+                }
+                if cut.start > start {
+                    difference.push(Interval::new(start, cut.start));
+                }
+                start = start.max(cut.end);
+                if cut.end > interval.end {
+                    break;
+                }
+                j += 1;
+            }
+            if start < interval.end {
+                difference.push(Interval::new(start, interval.end));
+            }
+        }
+        IntervalSet {
+            intervals: difference,
+        }
+    }
+
+    /// The parts below `pivot` and from `pivot` on.
+    pub fn split_at(&self, pivot: i64) -> (Self, Self) {
+        let mut below = Self::new();
+        let mut above = Self::new();
+        for interval in self.iter() {
+            let (low, high) = interval.split_at(pivot);
+            below.insert(low);
+            above.insert(high);
+        }
+        (below, above)
+    }
+
+    /// Every integer in the set plus `offset`.
+    pub fn offset(&self, offset: i64) -> Self {
+        self.iter()
+            .map(|interval| interval.offset(offset))
+            .collect()
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+/// Moves each integer by the offset of the first interval it falls in, and
+/// leaves the ones in no interval where they are.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct OffsetMap {
+    entries: Vec<(Interval, i64)>,
+}
+
+impl OffsetMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `source` to `source.offset(offset)`, where it isn't already
+    /// mapped.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        self.entries.push((source, offset));
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        self.entries
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    /// Where every integer in `set` ends up.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut unmapped = set.clone();
+        let mut mapped = IntervalSet::new();
+        for &(source, offset) in &self.entries {
+            let hit = unmapped.intersection(&source.into());
+            for interval in hit.iter() {
+                mapped.insert(interval.offset(offset));
+            }
+            unmapped = unmapped.difference(&hit);
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+/// An interval along each of `N` axes: the points whose every coordinate is
+/// in its axis's interval.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Hyperrectangle<const N: usize>(pub [Interval; N]);
+
+impl<const N: usize> Hyperrectangle<N> {
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(|interval| interval.is_empty())
+    }
+
+    /// How many points are inside.
+    pub fn volume(&self) -> u64 {
+        self.0.iter().map(|interval| interval.len()).product()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.0
+            .iter()
+            .zip(point)
+            .all(|(interval, value)| interval.contains(value))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut intersection = *self;
+        for (interval, &other) in intersection.0.iter_mut().zip(&other.0) {
+            *interval = interval.intersection(other);
+        }
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// The parts below `pivot` and from `pivot` on along `axis`, if they
+    /// aren't empty.
+    pub fn split_at(&self, axis: usize, pivot: i64) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.0[axis].split_at(pivot);
+        let with_axis = |interval: Interval| {
+            let mut part = *self;
+            part.0[axis] = interval;
+            (!part.is_empty()).then_some(part)
+        };
+        (with_axis(below), with_axis(above))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn interval_test() {
+        let interval = Interval::from(2..=5);
+        assert_eq!(interval, Interval::new(2, 6));
+        assert_eq!(interval, Interval::with_len(2, 4));
+        assert_eq!(interval.last(), Some(5));
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(5) && !interval.contains(6));
+        assert_eq!(Interval::new(4, 1), Interval::EMPTY);
+        assert_eq!(Interval::EMPTY.last(), None);
+        assert_eq!(interval.intersection((4..9).into()), (4..6).into());
+        assert!(!interval.overlaps((6..9).into()));
+        assert_eq!(
+            interval.difference((3..4).into()),
+            ((2..3).into(), (4..6).into())
+        );
+        assert_eq!(interval.split_at(0), (Interval::EMPTY, interval));
+        assert_eq!(interval.offset(-2), (0..4).into());
+
+        let mut set: IntervalSet = [(0..3).into(), (10..12).into()].into_iter().collect();
+        set.insert((3..5).into());
+        assert_eq!(set.intervals(), [(0..5).into(), (10..12).into()]);
+        set.insert((4..11).into());
+        assert_eq!(set.intervals(), [(0..12).into()]);
+        let holes: IntervalSet = [(2..4).into(), (6..=7).into(), (11..20).into()]
+            .into_iter()
+            .collect();
+        let difference = set.difference(&holes);
+        assert_eq!(
+            difference.intervals(),
+            [(0..2).into(), (4..6).into(), (8..11).into()]
+        );
+        assert_eq!(difference.len(), 7);
+        assert_eq!(set.intersection(&holes).len(), 5);
+        assert_eq!(
+            difference.union(&holes),
+            IntervalSet::from(Interval::new(0, 20))
+        );
+        assert!(difference.contains(9) && !difference.contains(11));
+        let (below, above) = difference.split_at(5);
+        assert_eq!((below.max(), above.min()), (Some(4), Some(5)));
+
+        // The example almanac's seed-to-soil map.
+        let mut map = OffsetMap::new();
+        map.insert(Interval::with_len(98, 2), 50 - 98);
+        map.insert(Interval::with_len(50, 48), 52 - 50);
+        assert_eq!([79, 14, 55, 13].map(|seed| map.map(seed)), [81, 14, 57, 13]);
+        let soil = map.map_set(&[(79..93).into(), (96..102).into()].into_iter().collect());
+        assert_eq!(
+            soil.intervals(),
+            [(50..52).into(), (81..95).into(), (98..102).into()]
+        );
+
+        let cube = Hyperrectangle([(1..=4000).into(); 4]);
+        assert_eq!(cube.volume(), 4000u64.pow(4));
+        let (low, high) = cube.split_at(1, 2091);
+        assert_eq!(
+            low.unwrap().volume() + high.unwrap().volume(),
+            cube.volume()
+        );
+        assert!(high.unwrap().contains([1, 2091, 1, 1]));
+        assert_eq!(cube.split_at(0, 1), (None, Some(cube)));
+        assert_eq!(low.unwrap().intersection(&high.unwrap()), None);
+    }
+
+    fn interval() -> impl Strategy<Value = Interval> {
+        (-20..20i64, 0..10u64).prop_map(|(start, len)| Interval::with_len(start, len))
+    }
+
+    fn set() -> impl Strategy<Value = IntervalSet> {
+        prop::collection::vec(interval(), 0..5).prop_map(IntervalSet::from_iter)
+    }
+
+    proptest! {
+        #[test]
+        fn set_operations_match_membership(a in set(), b in set(), pivot in -20..30i64) {
+            let (union, intersection, difference) = (a.union(&b), a.intersection(&b), a.difference(&b));
+            let (below, above) = a.split_at(pivot);
+            for value in -25..35 {
+                let (in_a, in_b) = (a.contains(value), b.contains(value));
+                prop_assert_eq!(union.contains(value), in_a || in_b);
+                prop_assert_eq!(intersection.contains(value), in_a && in_b);
+                prop_assert_eq!(difference.contains(value), in_a && !in_b);
+                prop_assert_eq!(below.contains(value), in_a && value < pivot);
+                prop_assert_eq!(above.contains(value), in_a && value >= pivot);
+            }
+            for set in [&union, &intersection, &difference] {
+                for pair in set.intervals().windows(2) {
+                    prop_assert!(pair[0].end() < pair[1].start());
+                }
+            }
+        }
+
+        #[test]
+        fn map_set_maps_every_value(sources in prop::collection::vec((interval(), -10..10i64), 0..4), values in set()) {
+            let mut map = OffsetMap::new();
+            for (source, offset) in sources {
+                map.insert(source, offset);
+            }
+            let mapped = map.map_set(&values);
+            let expected: IntervalSet = values
+                .iter()
+                .flat_map(Interval::iter)
+                .map(|value| Interval::with_len(map.map(value), 1))
+                .collect();
+            prop_assert_eq!(mapped, expected);
+        }
+    }
+}
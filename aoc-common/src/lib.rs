@@ -0,0 +1,16 @@
+pub mod cycle;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod params;
+pub mod parse;
+pub mod search;
+mod solution;
+
+pub use geometry::{Direction, Point, Position};
+pub use grid::Grid;
+pub use params::{Param, Params};
+pub use solution::Solution;
@@ -0,0 +1,227 @@
+//! Number theory for the puzzles that come down to cycles lining up.
+
+/// Greatest common divisor. `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it doesn't fit in a `u64`. It's 0 if
+/// either is 0.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all of them, 1 if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) >= 0` and `a * x + b * y = g`, or `None`
+/// if one of them doesn't fit in an `i64`, as for `gcd(i64::MIN, 0) = 2^63`.
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd_wide(a.into(), b.into());
+    Some((
+        i64::try_from(g).ok()?,
+        i64::try_from(x).ok()?,
+        i64::try_from(y).ok()?,
+    ))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and
+/// `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let modulus = i128::from(modulus);
+    let (g, x, _) = extended_gcd_wide(i128::from(a).rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus) as u64)
+}
+
+/// `base.pow(exponent) % modulus` without overflowing.
+///
+/// Panics if `modulus` is 0.
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    assert_ne!(modulus, 0, "modulus must be positive");
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// Floor of the square root, exact for every `u64` where going through
+/// `f64` isn't.
+pub fn isqrt(n: u64) -> u64 {
+    n.isqrt()
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` at once,
+/// with moduli that needn't be coprime. Gives `(x, m)` where every solution
+/// is `x + k * m` and `x` is in `0..m`, or `None` if the congruences
+/// contradict each other, a modulus is 0, or `m` doesn't fit in a `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, u64)>) -> Option<(u64, u64)> {
+    let mut combined: (i128, i128) = (0, 1);
+    for (residue, modulus) in congruences {
+        if modulus == 0 {
+            return None;
+        }
+        let modulus = i128::from(modulus);
+        let residue = i128::from(residue).rem_euclid(modulus);
+        let (combined_residue, combined_modulus) = combined;
+        let (g, inverse, _) = extended_gcd_wide(combined_modulus, modulus);
+        let difference = residue - combined_residue;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let lcm = combined_modulus
+            .checked_mul(step)
+            .filter(|&lcm| lcm <= i128::from(u64::MAX))?;
+        // combined_modulus * inverse ≡ g (mod modulus), so this many
+        // combined_moduli gets from combined_residue to residue. Both factors
+        // are below 2^64, so their product fits in a u128.
+        let k = ((difference / g).rem_euclid(step) as u128 * inverse.rem_euclid(step) as u128
+            % step as u128) as i128;
+        combined = (
+            (combined_residue + combined_modulus * k).rem_euclid(lcm),
+            lcm,
+        );
+    }
+    Some((combined.0 as u64, combined.1 as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn math_test() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 12), 12);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(extended_gcd(240, 46), Some((2, -9, 47)));
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(extended_gcd(i64::MIN, 3), Some((1, 1, 3074457345618258603)));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(2, 0, 1), 0);
+        assert_eq!(isqrt(u64::MAX), u64::from(u32::MAX));
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides_both(a in any::<u64>(), b in any::<u64>()) {
+            let g = gcd(a, b);
+            if g != 0 {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn lcm_is_common_multiple(a in 1..=u32::MAX as u64, b in 1..=u32::MAX as u64) {
+            let l = lcm(a, b).unwrap();
+            prop_assert_eq!(l % a, 0);
+            prop_assert_eq!(l % b, 0);
+            prop_assert_eq!(u128::from(l) * u128::from(gcd(a, b)), u128::from(a) * u128::from(b));
+        }
+
+        #[test]
+        fn extended_gcd_is_bezout(a in any::<i32>(), b in any::<i32>()) {
+            let (g, x, y) = extended_gcd(a.into(), b.into()).unwrap();
+            prop_assert_eq!(g as u64, gcd(a.unsigned_abs().into(), b.unsigned_abs().into()));
+            let combination = i128::from(a) * i128::from(x) + i128::from(b) * i128::from(y);
+            prop_assert_eq!(combination, i128::from(g));
+        }
+
+        #[test]
+        fn mod_inverse_inverts(a in any::<i64>(), modulus in 1..u64::MAX) {
+            match mod_inverse(a, modulus) {
+                Some(inverse) => {
+                    let product = i128::from(a) * i128::from(inverse);
+                    prop_assert_eq!(product.rem_euclid(modulus.into()), 1 % i128::from(modulus));
+                }
+                None => prop_assert_ne!(gcd(a.unsigned_abs(), modulus), 1),
+            }
+        }
+
+        #[test]
+        fn mod_pow_matches_repeated_multiplication(
+            base in any::<u64>(),
+            exponent in 0..64u64,
+            modulus in 1..u64::MAX,
+        ) {
+            let expected = (0..exponent).fold(1 % u128::from(modulus), |acc, _| {
+                acc * u128::from(base) % u128::from(modulus)
+            });
+            prop_assert_eq!(u128::from(mod_pow(base, exponent, modulus)), expected);
+        }
+
+        #[test]
+        fn isqrt_is_floor(n in any::<u64>()) {
+            let root = u128::from(isqrt(n));
+            prop_assert!(root * root <= u128::from(n));
+            prop_assert!((root + 1) * (root + 1) > u128::from(n));
+        }
+
+        #[test]
+        fn crt_solves_every_congruence(
+            x in 0..1_000_000i64,
+            moduli in prop::collection::vec(1..1000u64, 1..5),
+        ) {
+            let congruences: Vec<(i64, u64)> = moduli
+                .iter()
+                .map(|&modulus| (x % modulus as i64, modulus))
+                .collect();
+            let (residue, modulus) = crt(congruences.iter().copied()).unwrap();
+            prop_assert_eq!(Some(modulus), lcm_all(moduli.iter().copied()));
+            prop_assert_eq!(residue, x as u64 % modulus);
+            for (expected, modulus) in congruences {
+                prop_assert_eq!(residue % modulus, expected as u64);
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::{fmt, str::FromStr};
+
+/// A value the puzzle text fixes rather than the input, such as how many
+/// steps to take, that a solution can be told to use instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value the puzzle asks for.
+    pub default: &'static str,
+    pub about: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// The solution declares no parameter of this name.
+    Unknown(String),
+    /// The value can't be read as what the solution needs, for the given
+    /// reason.
+    Invalid {
+        name: String,
+        value: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Unknown(name) => write!(f, "no parameter called {name}"),
+            ParamError::Invalid {
+                name,
+                value,
+                reason,
+            } => write!(f, "{name} can't be {value}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// The value of every parameter a solution declares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, String)>,
+}
+
+impl Params {
+    /// The puzzle's own value for each of `declared`.
+    pub fn defaults(declared: &[Param]) -> Self {
+        Params {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.default.to_owned()))
+                .collect(),
+        }
+    }
+
+    /// The defaults of `declared`, with each `(name, value)` of `overrides`
+    /// set in turn.
+    pub fn new(declared: &[Param], overrides: &[(String, String)]) -> Result<Self, ParamError> {
+        let mut params = Params::defaults(declared);
+        for (name, value) in overrides {
+            params.set(name, value.clone())?;
+        }
+        Ok(params)
+    }
+
+    /// Replaces the value of `name`.
+    pub fn set(&mut self, name: &str, value: String) -> Result<(), ParamError> {
+        let (_, slot) = self
+            .values
+            .iter_mut()
+            .find(|(declared, _)| *declared == name)
+            .ok_or_else(|| ParamError::Unknown(name.to_owned()))?;
+        *slot = value;
+        Ok(())
+    }
+
+    /// The value of `name`, read as a `T`.
+    pub fn get<T>(&self, name: &str) -> Result<T, ParamError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let (_, value) = self
+            .values
+            .iter()
+            .find(|(declared, _)| *declared == name)
+            .ok_or_else(|| ParamError::Unknown(name.to_owned()))?;
+        value.parse().map_err(|err: T::Err| ParamError::Invalid {
+            name: name.to_owned(),
+            value: value.clone(),
+            reason: err.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_test() {
+        const DECLARED: &[Param] = &[
+            Param {
+                name: "steps",
+                default: "64",
+                about: "how far to walk",
+            },
+            Param {
+                name: "target",
+                default: "rx",
+                about: "where to stop",
+            },
+        ];
+        let defaults = Params::defaults(DECLARED);
+        assert_eq!(defaults.get("steps"), Ok(64));
+        assert_eq!(defaults.get("target"), Ok("rx".to_owned()));
+        assert_eq!(
+            defaults.get::<usize>("speed"),
+            Err(ParamError::Unknown("speed".to_owned()))
+        );
+
+        let overridden = Params::new(DECLARED, &[("steps".to_owned(), "6".to_owned())]).unwrap();
+        assert_eq!(overridden.get("steps"), Ok(6));
+        assert_eq!(overridden.get("target"), Ok("rx".to_owned()));
+
+        let invalid = Params::new(DECLARED, &[("steps".to_owned(), "-6".to_owned())]).unwrap();
+        assert_eq!(
+            invalid.get::<usize>("steps"),
+            Err(ParamError::Invalid {
+                name: "steps".to_owned(),
+                value: "-6".to_owned(),
+                reason: "invalid digit found in string".to_owned(),
+            })
+        );
+        assert_eq!(
+            Params::new(DECLARED, &[("speed".to_owned(), "1".to_owned())]),
+            Err(ParamError::Unknown("speed".to_owned()))
+        );
+    }
+}
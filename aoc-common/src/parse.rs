@@ -0,0 +1,489 @@
+//! Small parser combinators over a cursor into the input, so a malformed
+//! line fails with where it went wrong instead of a panic.
+//!
+//! A parser is any `Fn(&mut Cursor) -> ParseResult<T>`. On success it moves
+//! the cursor past what it read; on failure the cursor is left where it is
+//! and the error points at the first character that didn't fit.
+
+use crate::Grid;
+use std::{error::Error, fmt, str::FromStr};
+
+/// Where parsing stopped, as a 1-based line and column (in characters), and
+/// what was expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Something that reads a `T` from the cursor.
+pub trait Parser<'a, T>: Fn(&mut Cursor<'a>) -> ParseResult<T> {}
+
+impl<'a, T, F: Fn(&mut Cursor<'a>) -> ParseResult<T>> Parser<'a, T> for F {}
+
+/// The whole input and how far into it parsing has got.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+    line_start: usize,
+    /// The furthest error a list backed out of, which is likely the real
+    /// reason for any input left over at the end.
+    abandoned: Option<ParseError>,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Cursor {
+            input,
+            offset: 0,
+            line: 1,
+            line_start: 0,
+            abandoned: None,
+        }
+    }
+
+    /// The input not yet parsed.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.input.len()
+    }
+
+    /// The line and column of the next character, both from 1.
+    pub fn position(&self) -> (usize, usize) {
+        (
+            self.line,
+            self.input[self.line_start..self.offset].chars().count() + 1,
+        )
+    }
+
+    /// An error at the next character.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.position();
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Takes the next `len` bytes.
+    fn advance(&mut self, len: usize) -> &'a str {
+        let taken = &self.input[self.offset..self.offset + len];
+        for (index, _) in taken.match_indices('\n') {
+            self.line += 1;
+            self.line_start = self.offset + index + 1;
+        }
+        self.offset += len;
+        taken
+    }
+
+    fn mark(&self) -> Mark {
+        Mark {
+            offset: self.offset,
+            line: self.line,
+            line_start: self.line_start,
+        }
+    }
+
+    fn rewind(&mut self, mark: Mark) {
+        self.offset = mark.offset;
+        self.line = mark.line;
+        self.line_start = mark.line_start;
+    }
+
+    fn abandon(&mut self, error: ParseError) {
+        if self
+            .abandoned
+            .as_ref()
+            .is_none_or(|furthest| (furthest.line, furthest.column) <= (error.line, error.column))
+        {
+            self.abandoned = Some(error);
+        }
+    }
+}
+
+/// A position to rewind the cursor to.
+#[derive(Clone, Copy)]
+struct Mark {
+    offset: usize,
+    line: usize,
+    line_start: usize,
+}
+
+/// Runs `parser` over the whole of `input`. Only whitespace may be left over.
+pub fn parse<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> ParseResult<T> {
+    let mut cursor = Cursor::new(input);
+    let parsed = parser(&mut cursor)?;
+    cursor.advance(cursor.rest().len() - cursor.rest().trim_start().len());
+    if cursor.is_at_end() {
+        return Ok(parsed);
+    }
+    let leftover = cursor.error("end of input");
+    match cursor.abandoned {
+        Some(abandoned)
+            if (abandoned.line, abandoned.column) >= (leftover.line, leftover.column) =>
+        {
+            Err(abandoned)
+        }
+        _ => Err(leftover),
+    }
+}
+
+/// Exactly `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    move |cursor: &mut Cursor<'a>| {
+        if cursor.rest().starts_with(expected) {
+            Ok(cursor.advance(expected.len()))
+        } else {
+            Err(cursor.error(format!("{expected:?}")))
+        }
+    }
+}
+
+/// The longest non-empty run of characters matching `accept`. `what` names
+/// it in errors.
+pub fn take_while1<'a>(
+    what: &'static str,
+    accept: impl Fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    move |cursor: &mut Cursor<'a>| {
+        let rest = cursor.rest();
+        let len = rest.find(|c| !accept(c)).unwrap_or(rest.len());
+        if len == 0 {
+            Err(cursor.error(what))
+        } else {
+            Ok(cursor.advance(len))
+        }
+    }
+}
+
+/// A run of letters and digits, such as a name.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("a word", char::is_alphanumeric)
+}
+
+/// One or more spaces, as between numbers in a row.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("a space", |c| c == ' ')
+}
+
+/// Decimal digits, read as a `T`.
+pub fn unsigned<'a, T: FromStr>() -> impl Parser<'a, T> {
+    number(take_while1("a number", |c| c.is_ascii_digit()))
+}
+
+/// Decimal digits with an optional sign, read as a `T`.
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, T> {
+    number(move |cursor: &mut Cursor<'a>| {
+        let start = cursor.offset;
+        let rest = cursor.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(cursor.error("a number"));
+        }
+        cursor.advance(sign + digits);
+        Ok(&cursor.input[start..cursor.offset])
+    })
+}
+
+fn number<'a, T: FromStr>(digits: impl Parser<'a, &'a str>) -> impl Parser<'a, T> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = cursor.mark();
+        digits(cursor)?.parse().map_err(|_| {
+            cursor.rewind(start);
+            cursor.error("a number in range")
+        })
+    }
+}
+
+/// `parser`'s result passed through `f`.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |cursor: &mut Cursor<'a>| parser(cursor).map(&f)
+}
+
+/// `parser`'s result passed through `f`, which may refuse it. A refusal is
+/// reported where `parser` started, expecting `f`'s error.
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> Result<U, String>,
+) -> impl Parser<'a, U> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = cursor.mark();
+        f(parser(cursor)?).map_err(|expected| {
+            cursor.rewind(start);
+            cursor.error(expected)
+        })
+    }
+}
+
+/// `first` then `second`.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |cursor: &mut Cursor<'a>| attempt(cursor, |cursor| Ok((first(cursor)?, second(cursor)?)))
+}
+
+/// `prefix` then `parser`, keeping only the latter.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(prefix, parser), |(_, parsed)| parsed)
+}
+
+/// `parser` then `suffix`, keeping only the former.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, suffix), |(parsed, _)| parsed)
+}
+
+/// `parser` between `open` and `close`.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// A `key`, the `separator`, then a `value`, such as `seeds: 79 14`.
+pub fn key_value<'a, K, S, V>(
+    key: impl Parser<'a, K>,
+    separator: impl Parser<'a, S>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    pair(terminated(key, separator), value)
+}
+
+/// `parser` if it matches, otherwise nothing and no input used.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |cursor: &mut Cursor<'a>| Ok(parser(cursor).ok())
+}
+
+/// `first`, or `second` if `first` doesn't match. When neither does, the
+/// error is from whichever got further.
+pub fn alt<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |cursor: &mut Cursor<'a>| {
+        first(cursor).or_else(|first_error| {
+            second(cursor).map_err(|second_error| {
+                if (first_error.line, first_error.column) > (second_error.line, second_error.column)
+                {
+                    first_error
+                } else {
+                    second_error
+                }
+            })
+        })
+    }
+}
+
+/// One or more `item`s with `separator` between them.
+///
+/// The list ends at the first separator not followed by an item. An item
+/// that fails partway through, though, is an error: it was clearly meant to
+/// be there.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut items = vec![item(cursor)?];
+        loop {
+            let before = cursor.mark();
+            if separator(cursor).is_err() {
+                return Ok(items);
+            }
+            let item_start = cursor.position();
+            match item(cursor) {
+                Ok(parsed) => items.push(parsed),
+                Err(error) if (error.line, error.column) == item_start => {
+                    cursor.rewind(before);
+                    cursor.abandon(error);
+                    return Ok(items);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+/// One `item` per line.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, tag("\n"))
+}
+
+/// One `item` per block of lines, the blocks split by blank lines.
+pub fn sections<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, tag("\n\n"))
+}
+
+/// A block of equally long lines, one tile per character. `tile` refuses
+/// characters with `None`; `what` names the tiles in errors.
+pub fn grid<'a, T>(
+    what: &'static str,
+    tile: impl Fn(char) -> Option<T>,
+) -> impl Parser<'a, Grid<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = cursor.mark();
+        let mut width = None;
+        let mut cells = Vec::new();
+        loop {
+            let rest = cursor.rest();
+            let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+            if line.is_empty() {
+                break;
+            }
+            if let Some(width) = width.filter(|&width| width != line.chars().count()) {
+                let error = cursor.error(format!("a row of {width} {what}"));
+                cursor.rewind(start);
+                return Err(error);
+            }
+            width = Some(line.chars().count());
+            for c in line.chars() {
+                match tile(c) {
+                    Some(parsed) => cells.push(parsed),
+                    None => {
+                        let error = cursor.error(what);
+                        cursor.rewind(start);
+                        return Err(error);
+                    }
+                }
+                cursor.advance(c.len_utf8());
+            }
+            if !cursor.rest().starts_with("\n") || cursor.rest().starts_with("\n\n") {
+                break;
+            }
+            cursor.advance(1);
+        }
+        match width {
+            Some(width) => Ok(Grid::from_cells(width, cells)),
+            None => Err(cursor.error(what)),
+        }
+    }
+}
+
+/// Runs `parser`, putting the cursor back where it was if it fails.
+fn attempt<'a, T>(
+    cursor: &mut Cursor<'a>,
+    parser: impl FnOnce(&mut Cursor<'a>) -> ParseResult<T>,
+) -> ParseResult<T> {
+    let start = cursor.mark();
+    parser(cursor).inspect_err(|_| cursor.rewind(start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let node = pair(
+            terminated(word(), tag(" = ")),
+            delimited(
+                tag("("),
+                pair(terminated(word(), tag(", ")), word()),
+                tag(")"),
+            ),
+        );
+        assert_eq!(
+            parse("AAA = (BBB, CCC)\nBBB = (DDD, EEE)\n", lines(&node)),
+            Ok(vec![("AAA", ("BBB", "CCC")), ("BBB", ("DDD", "EEE"))])
+        );
+        assert_eq!(
+            parse("AAA = (BBB, CCC)\nBBB = (DDD EEE)", lines(&node))
+                .unwrap_err()
+                .to_string(),
+            "2:11: expected \", \""
+        );
+        assert_eq!(
+            parse("AAA = (BBB, CCC)\n?", lines(&node))
+                .unwrap_err()
+                .to_string(),
+            "2:1: expected a word"
+        );
+
+        let row = key_value(
+            word(),
+            tag(":"),
+            preceded(spaces(), separated(signed::<i32>(), spaces())),
+        );
+        assert_eq!(
+            parse("Time:  7 -15   +30", &row),
+            Ok(("Time", vec![7, -15, 30]))
+        );
+        assert_eq!(
+            parse("Time:  7 300000000000", &row),
+            Err(ParseError {
+                line: 1,
+                column: 10,
+                expected: "a number in range".to_string()
+            })
+        );
+        assert!(parse::<u8>("-3", unsigned()).is_err());
+
+        let blocks = sections(grid("'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }));
+        let grids = parse("#.\n.#\n\n##\n", &blocks).unwrap();
+        assert_eq!(grids.len(), 2);
+        assert!(grids[0][(1, 1)] && !grids[0][(1, 0)]);
+        assert_eq!(
+            parse("#.\n.#\n\n##\n#", &blocks).unwrap_err().to_string(),
+            "5:1: expected a row of 2 '#' or '.'"
+        );
+        assert_eq!(
+            parse("#.\n.x", &blocks).unwrap_err().to_string(),
+            "2:2: expected '#' or '.'"
+        );
+
+        let sign = alt(map(tag("+"), |_| 1), map(tag("-"), |_| -1));
+        assert_eq!(parse("-", &sign), Ok(-1));
+        assert_eq!(
+            parse("*", &sign).unwrap_err().to_string(),
+            "1:1: expected \"-\""
+        );
+        let even = try_map(unsigned::<u32>(), |n| {
+            if n % 2 == 0 {
+                Ok(n)
+            } else {
+                Err("an even number".to_string())
+            }
+        });
+        assert_eq!(
+            parse("7", &even).unwrap_err().to_string(),
+            "1:1: expected an even number"
+        );
+        assert_eq!(
+            parse("x", opt(tag("y"))).unwrap_err().to_string(),
+            "1:1: expected end of input"
+        );
+    }
+}
@@ -0,0 +1,476 @@
+//! Breadth-first, Dijkstra, A* and bidirectional search over any state type,
+//! given a function from a state to the states it leads to.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+    ops::Add,
+};
+
+/// The cheapest way found to a state: its cost from the start and the state
+/// before it, `None` for the start itself.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Visit<S, C> {
+    pub cost: C,
+    pub from: Option<S>,
+}
+
+/// Where a search keeps the states it has seen.
+pub trait Visited<S, C> {
+    fn get(&self, state: &S) -> Option<&Visit<S, C>>;
+
+    fn insert(&mut self, state: S, visit: Visit<S, C>);
+}
+
+/// Works for any hashable state.
+pub type HashVisited<S, C> = HashMap<S, Visit<S, C>>;
+
+impl<S: Eq + Hash, C> Visited<S, C> for HashMap<S, Visit<S, C>> {
+    fn get(&self, state: &S) -> Option<&Visit<S, C>> {
+        HashMap::get(self, state)
+    }
+
+    fn insert(&mut self, state: S, visit: Visit<S, C>) {
+        HashMap::insert(self, state, visit);
+    }
+}
+
+/// Keeps visits in a vector, for states that number `0..len`, like the tiles
+/// of a grid. Much quicker than hashing them.
+pub struct Dense<S, C, F> {
+    visits: Vec<Option<Visit<S, C>>>,
+    index: F,
+}
+
+impl<S, C, F: Fn(&S) -> usize> Dense<S, C, F> {
+    pub fn new(len: usize, index: F) -> Self {
+        Dense {
+            visits: std::iter::repeat_with(|| None).take(len).collect(),
+            index,
+        }
+    }
+}
+
+impl<S, C, F: Fn(&S) -> usize> Visited<S, C> for Dense<S, C, F> {
+    fn get(&self, state: &S) -> Option<&Visit<S, C>> {
+        self.visits[(self.index)(state)].as_ref()
+    }
+
+    fn insert(&mut self, state: S, visit: Visit<S, C>) {
+        let index = (self.index)(&state);
+        self.visits[index] = Some(visit);
+    }
+}
+
+/// The states a path goes through, from the start to the end, and what it
+/// costs.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// What a search found: the goal, if it got to one, and every state it
+/// visited on the way.
+pub struct Search<S, C, V> {
+    visited: V,
+    goal: Option<S>,
+    cost: PhantomData<C>,
+}
+
+impl<S: Clone, C: Copy, V: Visited<S, C>> Search<S, C, V> {
+    fn new(visited: V, goal: Option<S>) -> Self {
+        Search {
+            visited,
+            goal,
+            cost: PhantomData,
+        }
+    }
+
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// Whether the search got to `state` before it stopped.
+    pub fn contains(&self, state: &S) -> bool {
+        self.visited.get(state).is_some()
+    }
+
+    /// The cost of the cheapest way to `state` the search found.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        Some(self.visited.get(state)?.cost)
+    }
+
+    /// The way from the start to `state`.
+    pub fn path_to(&self, state: &S) -> Option<Path<S, C>> {
+        let cost = self.cost(state)?;
+        let mut states = vec![state.clone()];
+        while let Some(from) = &self.visited.get(states.last()?)?.from {
+            states.push(from.clone());
+        }
+        states.reverse();
+        Some(Path { cost, states })
+    }
+
+    /// The way from the start to the goal.
+    pub fn path(&self) -> Option<Path<S, C>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth-first search from `start` that stops at the first state
+/// `is_goal` accepts. Every step costs 1. An `is_goal` that accepts nothing
+/// visits everything reachable.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize, HashVisited<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_in(HashMap::new(), start, successors, is_goal)
+}
+
+/// `bfs`, keeping visited states in `visited`.
+pub fn bfs_in<S, V, I>(
+    mut visited: V,
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize, V>
+where
+    S: Clone,
+    V: Visited<S, usize>,
+    I: IntoIterator<Item = S>,
+{
+    visited.insert(
+        start.clone(),
+        Visit {
+            cost: 0,
+            from: None,
+        },
+    );
+    if is_goal(&start) {
+        return Search::new(visited, Some(start));
+    }
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, cost)) = queue.pop_front() {
+        for next in successors(&state) {
+            if visited.get(&next).is_some() {
+                continue;
+            }
+            visited.insert(
+                next.clone(),
+                Visit {
+                    cost: cost + 1,
+                    from: Some(state.clone()),
+                },
+            );
+            if is_goal(&next) {
+                return Search::new(visited, Some(next));
+            }
+            queue.push_back((next, cost + 1));
+        }
+    }
+    Search::new(visited, None)
+}
+
+/// A state waiting in the open set, ordered so the heap pops the lowest
+/// priority first.
+struct Open<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Open<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Open<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Open<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Open<S, C> {}
+
+/// Dijkstra's algorithm from `start`, stopping once the cheapest state left
+/// is one `is_goal` accepts. `successors` gives each next state with the
+/// cost of getting there, which mustn't be negative. `C::default()` is
+/// taken as zero cost.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C, HashVisited<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_in(HashMap::new(), start, successors, |_| C::default(), is_goal)
+}
+
+/// `dijkstra`, keeping visited states in `visited`.
+pub fn dijkstra_in<S, C, V, I>(
+    visited: V,
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C, V>
+where
+    S: Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    V: Visited<S, C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_in(visited, start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search: Dijkstra's algorithm, but trying the states that `heuristic`
+/// thinks are closest to a goal first. The heuristic mustn't overestimate
+/// the cost to the nearest goal, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C, HashVisited<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_in(HashMap::new(), start, successors, heuristic, is_goal)
+}
+
+/// `astar`, keeping visited states in `visited`.
+pub fn astar_in<S, C, V, I>(
+    mut visited: V,
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C, V>
+where
+    S: Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    V: Visited<S, C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut open = BinaryHeap::new();
+    visited.insert(
+        start.clone(),
+        Visit {
+            cost: C::default(),
+            from: None,
+        },
+    );
+    open.push(Open {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    });
+    while let Some(Open { cost, state, .. }) = open.pop() {
+        // A cheaper way here was found after this one was queued.
+        if visited.get(&state).is_some_and(|visit| visit.cost < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Search::new(visited, Some(state));
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if visited
+                .get(&next)
+                .is_some_and(|visit| visit.cost <= next_cost)
+            {
+                continue;
+            }
+            visited.insert(
+                next.clone(),
+                Visit {
+                    cost: next_cost,
+                    from: Some(state.clone()),
+                },
+            );
+            open.push(Open {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+    Search::new(visited, None)
+}
+
+/// The shortest path from `start` to `goal`, searching breadth first from
+/// both ends until they meet. `predecessors` gives the states that lead to
+/// a state, the same as `successors` when the graph is undirected.
+pub fn bidirectional_bfs<S, I, J>(
+    start: S,
+    goal: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut predecessors: impl FnMut(&S) -> J,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    J: IntoIterator<Item = S>,
+{
+    if start == goal {
+        return Some(Path {
+            cost: 0,
+            states: vec![start],
+        });
+    }
+    // Each state's distance from its end and the state before it on the
+    // way from that end.
+    let mut forward: HashMap<S, (usize, Option<S>)> = HashMap::from([(start.clone(), (0, None))]);
+    let mut backward: HashMap<S, (usize, Option<S>)> = HashMap::from([(goal.clone(), (0, None))]);
+    let mut forward_frontier = vec![start];
+    let mut backward_frontier = vec![goal];
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        let is_forward = forward_frontier.len() <= backward_frontier.len();
+        let (frontier, seen, other) = if is_forward {
+            (&mut forward_frontier, &mut forward, &backward)
+        } else {
+            (&mut backward_frontier, &mut backward, &forward)
+        };
+        // Finish the whole level: the first meeting found in it needn't be
+        // the shortest one.
+        let mut meeting: Option<(usize, S)> = None;
+        let mut next_frontier = Vec::new();
+        for state in frontier.drain(..) {
+            let distance = seen[&state].0 + 1;
+            let neighbors: Vec<S> = if is_forward {
+                successors(&state).into_iter().collect()
+            } else {
+                predecessors(&state).into_iter().collect()
+            };
+            for next in neighbors {
+                if seen.contains_key(&next) {
+                    continue;
+                }
+                seen.insert(next.clone(), (distance, Some(state.clone())));
+                if let Some(&(other_distance, _)) = other.get(&next) {
+                    let length = distance + other_distance;
+                    if meeting.as_ref().is_none_or(|(best, _)| length < *best) {
+                        meeting = Some((length, next.clone()));
+                    }
+                }
+                next_frontier.push(next);
+            }
+        }
+        *frontier = next_frontier;
+        if let Some((cost, meeting)) = meeting {
+            let chain = |seen: &HashMap<S, (usize, Option<S>)>| {
+                let mut states = vec![meeting.clone()];
+                while let Some(from) = &seen[states.last().unwrap()].1 {
+                    states.push(from.clone());
+                }
+                states
+            };
+            let mut states = chain(&forward);
+            states.reverse();
+            states.extend(chain(&backward).into_iter().skip(1));
+            return Some(Path { cost, states });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_test() {
+        // 0 -> 1 -> 2 -> 3 costs 3, 0 -> 3 directly costs 5, and 4 is
+        // unreachable.
+        let edges = [(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 3, 5), (4, 0, 1)];
+        let successors = |&state: &u32| {
+            edges
+                .iter()
+                .filter(move |&&(from, _, _)| from == state)
+                .map(|&(_, to, cost)| (to, cost))
+        };
+        let predecessors = |&state: &u32| {
+            edges
+                .iter()
+                .filter(move |&&(_, to, _)| to == state)
+                .map(|&(from, _, _)| from)
+        };
+        let unweighted = |state: &u32| successors(state).map(|(to, _)| to);
+
+        let breadth_first = bfs(0, unweighted, |&state| state == 3);
+        assert_eq!(
+            breadth_first.path(),
+            Some(Path {
+                cost: 1,
+                states: vec![0, 3]
+            })
+        );
+        let everything = bfs(0, unweighted, |_| false);
+        assert_eq!(everything.goal(), None);
+        assert_eq!(everything.cost(&2), Some(2));
+        assert!(!everything.contains(&4));
+
+        let cheapest = dijkstra(0, successors, |&state| state == 3);
+        assert_eq!(
+            cheapest.path(),
+            Some(Path {
+                cost: 3,
+                states: vec![0, 1, 2, 3]
+            })
+        );
+        let guided = astar(0, successors, |&state| 3 - state, |&state| state == 3);
+        assert_eq!(guided.path(), cheapest.path());
+        assert_eq!(
+            dijkstra(4, successors, |&state| state == 3)
+                .path()
+                .map(|path| path.cost),
+            Some(4)
+        );
+        assert!(dijkstra(1, successors, |&state| state == 4)
+            .path()
+            .is_none());
+
+        let dense = dijkstra_in(
+            Dense::new(5, |&state: &u32| state as usize),
+            0,
+            successors,
+            |_| false,
+        );
+        assert_eq!(dense.path_to(&3), cheapest.path());
+        let dense = bfs_in(
+            Dense::new(5, |&state: &u32| state as usize),
+            2,
+            unweighted,
+            |_| false,
+        );
+        assert_eq!(dense.path_to(&3).map(|path| path.states), Some(vec![2, 3]));
+
+        assert_eq!(
+            bidirectional_bfs(4, 2, unweighted, predecessors),
+            Some(Path {
+                cost: 3,
+                states: vec![4, 0, 1, 2]
+            })
+        );
+        assert_eq!(bidirectional_bfs(3, 0, unweighted, predecessors), None);
+    }
+}
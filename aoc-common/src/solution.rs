@@ -0,0 +1,32 @@
+use crate::{Param, Params};
+use std::fmt::Display;
+
+/// A single part of a single day's puzzle.
+///
+/// `parse` turns the raw puzzle input into whatever `solve` works on. Days
+/// that parse as they go just use `type Input<'a> = &'a str` and hand the
+/// input straight through. Either step refuses an input it can't make sense
+/// of with the day's own `Error`.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const PART: u8;
+    /// The puzzle's example input, if `solve` gives a meaningful answer for it
+    /// with its default parameters.
+    const EXAMPLE: Option<&'static str>;
+    /// Values from the puzzle text that `solve` can be given others for.
+    const PARAMS: &'static [Param] = &[];
+
+    type Input<'a>;
+    type Output: Display;
+    type Error: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error>;
+
+    /// `params` has a value for each of `PARAMS`.
+    fn solve(input: Self::Input<'_>, params: &Params) -> Result<Self::Output, Self::Error>;
+
+    fn run(input: &str, params: &Params) -> Result<Self::Output, Self::Error> {
+        Self::solve(Self::parse(input)?, params)
+    }
+}
@@ -0,0 +1,152 @@
+//! Benches `parse` and `solve` separately for every registered solution, on
+//! its real input and on the puzzle's example.
+//!
+//! Narrow it down with criterion's usual filter, e.g.
+//! `cargo bench -p aoc -- day-2023-16-part2`. Solutions that take seconds per run
+//! are only benched on their real input when `AOC_BENCH_SLOW` is set.
+//!
+//! Besides criterion's own per-benchmark reports, the latest estimate of every
+//! benchmark is collected into `summary.json` in the criterion output
+//! directory (`target/criterion`, or `$CRITERION_HOME`).
+
+use aoc::params;
+use aoc::registry::{Registered, SOLUTIONS};
+use aoc::runner::{self, Inputs};
+use criterion::{BenchmarkId, Criterion};
+use serde_json::{json, Value};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+const SLOW: &[(u16, u8, u8)] = &[(2023, 5, 2), (2023, 23, 2)];
+
+fn bench_solution(c: &mut Criterion, solution: &Registered, real_input: Option<&str>, slow: bool) {
+    let mut group = c.benchmark_group(format!(
+        "day-{}-{:02}-part{}",
+        solution.year, solution.day, solution.part
+    ));
+    if slow {
+        group.sample_size(10);
+    }
+    let params = match params::resolve(solution, &[]) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!(
+                "skipping {} day {} part {}: {err}",
+                solution.year, solution.day, solution.part
+            );
+            return;
+        }
+    };
+    for (name, input) in [("input", real_input), ("example", solution.example)] {
+        let Some(input) = input else {
+            continue;
+        };
+        if let Err(err) = runner::run_with_input(solution, input, &[]).answer {
+            eprintln!(
+                "skipping {} day {} part {} on its {name}: {err}",
+                solution.year, solution.day, solution.part
+            );
+            continue;
+        }
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter_custom(|iterations| (solution.time_parse)(input, iterations))
+        });
+        group.bench_with_input(BenchmarkId::new("solve", name), input, |b, input| {
+            b.iter_custom(|iterations| (solution.time_solve)(input, &params, iterations))
+        });
+    }
+    group.finish();
+}
+
+fn summarize(output_dir: &Path) -> io::Result<()> {
+    let mut benchmarks = Vec::new();
+    collect_estimates(output_dir, &mut benchmarks)?;
+    benchmarks.sort_by_key(|benchmark| benchmark["id"].as_str().unwrap_or_default().to_owned());
+    let summary = serde_json::to_string_pretty(&Value::Array(benchmarks))?;
+    fs::write(output_dir.join("summary.json"), summary + "\n")
+}
+
+/// Finds every `new/benchmark.json` under `dir` and pairs it with the
+/// estimates criterion saved next to it.
+fn collect_estimates(dir: &Path, benchmarks: &mut Vec<Value>) -> io::Result<()> {
+    let latest = dir.join("new");
+    if latest.join("benchmark.json").is_file() {
+        let benchmark = read_json(&latest.join("benchmark.json"))?;
+        let estimates = read_json(&latest.join("estimates.json"))?;
+        let Some(group) = benchmark["group_id"].as_str() else {
+            return Ok(());
+        };
+        let Some(((year, day), part)) = group
+            .strip_prefix("day-")
+            .and_then(|rest| rest.split_once("-part"))
+            .and_then(|(year_day, part)| Some((year_day.split_once('-')?, part)))
+        else {
+            return Ok(());
+        };
+        benchmarks.push(json!({
+            "id": benchmark["full_id"],
+            "year": year.parse::<u16>().ok(),
+            "day": day.parse::<u8>().ok(),
+            "part": part.parse::<u8>().ok(),
+            "phase": benchmark["function_id"],
+            "input": benchmark["value_str"],
+            "mean_ns": estimates["mean"]["point_estimate"],
+            "median_ns": estimates["median"]["point_estimate"],
+            "std_dev_ns": estimates["std_dev"]["point_estimate"],
+        }));
+        return Ok(());
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_estimates(&path, benchmarks)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_json(path: &Path) -> io::Result<Value> {
+    let contents = fs::read_to_string(path)?;
+    serde_json::from_str(&contents).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+fn main() {
+    runner::install_panic_hook();
+    let output_dir = env::var_os("CRITERION_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/criterion"));
+    let include_slow = env::var_os("AOC_BENCH_SLOW").is_some();
+    let mut criterion = Criterion::default()
+        .output_directory(&output_dir)
+        .configure_from_args();
+
+    let inputs = Inputs::PerDay(None);
+    for day_solutions in SOLUTIONS.chunk_by(|a, b| (a.year, a.day) == (b.year, b.day)) {
+        let (year, day) = (day_solutions[0].year, day_solutions[0].day);
+        let real_input = inputs.read(year, day);
+        if let Err(err) = &real_input {
+            eprintln!("skipping {year} day {day} on its input: {err}");
+        }
+        for solution in day_solutions {
+            let slow = SLOW.contains(&(year, day, solution.part));
+            if slow && !include_slow {
+                eprintln!(
+                    "skipping {year} day {day} part {} on its input: slow, set AOC_BENCH_SLOW to include it",
+                    solution.part
+                );
+            }
+            let real_input = real_input.as_deref().ok().filter(|_| include_slow || !slow);
+            bench_solution(&mut criterion, solution, real_input, slow);
+        }
+    }
+
+    criterion.final_summary();
+    if let Err(err) = summarize(&output_dir) {
+        eprintln!(
+            "couldn't write {}: {err}",
+            output_dir.join("summary.json").display()
+        );
+    }
+}
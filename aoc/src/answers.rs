@@ -0,0 +1,121 @@
+use crate::runner::day_dir;
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, ErrorKind, Write as _},
+    path::PathBuf,
+};
+
+const HEADER: &str = "# day part input-sha256 answer\n";
+
+/// The answer a part is known to give for one particular input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+pub fn answers_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("answers.txt")
+}
+
+/// The first 16 hex digits of the input's SHA-256, enough to tell inputs apart.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes()).iter().take(8).fold(
+        String::with_capacity(16),
+        |mut hash, byte| {
+            write!(hash, "{byte:02x}").unwrap();
+            hash
+        },
+    )
+}
+
+pub fn parse(text: &str) -> Result<Vec<Answer>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let malformed = || format!("line {}: expected `day part hash answer`", i + 1);
+            let mut fields = line.split_whitespace();
+            let day = fields.next().and_then(|day| day.parse().ok());
+            let part = fields.next().and_then(|part| part.parse().ok());
+            let input_hash = fields.next();
+            let answer = fields.next();
+            match (day, part, input_hash, answer, fields.next()) {
+                (Some(day), Some(part), Some(input_hash), Some(answer), None) => Ok(Answer {
+                    day,
+                    part,
+                    input_hash: input_hash.to_owned(),
+                    answer: answer.to_owned(),
+                }),
+                _ => Err(malformed()),
+            }
+        })
+        .collect()
+}
+
+/// Every recorded answer for the day, or none if it has no answers file yet.
+pub fn load(year: u16, day: u8) -> Result<Vec<Answer>, String> {
+    let path = answers_path(year, day);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("{}: {err}", path.display())),
+    }
+}
+
+pub fn record(year: u16, answer: &Answer) -> io::Result<()> {
+    let path = answers_path(year, answer.day);
+    let is_new = !path.exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if is_new {
+        file.write_all(HEADER.as_bytes())?;
+    }
+    writeln!(
+        file,
+        "{} {} {} {}",
+        answer.day, answer.part, answer.input_hash, answer.answer
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let answers = parse(
+            "# day part input-sha256 answer
+24 1 0123456789abcdef 12938
+
+24 2 0123456789abcdef 976976197397181",
+        )
+        .unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(
+            answers[1],
+            Answer {
+                day: 24,
+                part: 2,
+                input_hash: "0123456789abcdef".to_owned(),
+                answer: "976976197397181".to_owned(),
+            }
+        );
+        assert_eq!(
+            parse("24 1 0123456789abcdef").unwrap_err(),
+            "line 1: expected `day part hash answer`"
+        );
+    }
+
+    #[test]
+    fn input_hash_test() {
+        assert_eq!(input_hash(""), "e3b0c44298fc1c14");
+        assert_ne!(input_hash("1abc2"), input_hash("1abc2\n"));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::runner::day_dir;
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind, Write as _},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const HEADER: &str = "# day part input-sha256 unix-time verdict answer\n";
+
+/// How long to hold off after an answer that wasn't accepted, unless the site
+/// said exactly how long.
+pub const COOLDOWN: Duration = Duration::from_secs(60);
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Not judged, because the last answer was too recent. Holds how much
+    /// longer the site asked to wait.
+    RateLimited(Duration),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "rate-limited:{}", wait.as_secs()),
+        }
+    }
+}
+
+impl Verdict {
+    fn parse(text: &str) -> Option<Self> {
+        match text {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => {
+                let secs = text.strip_prefix("rate-limited:")?.parse().ok()?;
+                Some(Verdict::RateLimited(Duration::from_secs(secs)))
+            }
+        }
+    }
+}
+
+/// One answer sent to the site for one particular input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+pub fn attempts_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("attempts.txt")
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+pub fn parse(text: &str) -> Result<Vec<Attempt>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let malformed = || {
+                format!(
+                    "line {}: expected `day part hash time verdict answer`",
+                    i + 1
+                )
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, input_hash, time, verdict, answer] = fields[..] else {
+                return Err(malformed());
+            };
+            Ok(Attempt {
+                day: day.parse().map_err(|_| malformed())?,
+                part: part.parse().map_err(|_| malformed())?,
+                input_hash: input_hash.to_owned(),
+                time: time.parse().map_err(|_| malformed())?,
+                verdict: Verdict::parse(verdict).ok_or_else(malformed)?,
+                answer: answer.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Every attempt logged for the day, or none if it has no log yet.
+pub fn load(year: u16, day: u8) -> Result<Vec<Attempt>, String> {
+    let path = attempts_path(year, day);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("{}: {err}", path.display())),
+    }
+}
+
+pub fn record(year: u16, attempt: &Attempt) -> io::Result<()> {
+    let path = attempts_path(year, attempt.day);
+    let is_new = !path.exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if is_new {
+        file.write_all(HEADER.as_bytes())?;
+    }
+    writeln!(
+        file,
+        "{} {} {} {} {} {}",
+        attempt.day,
+        attempt.part,
+        attempt.input_hash,
+        attempt.time,
+        attempt.verdict,
+        attempt.answer
+    )
+}
+
+/// Why `answer` shouldn't be sent for this part and input, going by what the
+/// site already said about earlier attempts.
+pub fn refusal(attempts: &[Attempt], part: u8, input_hash: &str, answer: &str) -> Option<String> {
+    let judged: Vec<&Attempt> = attempts
+        .iter()
+        .filter(|attempt| {
+            attempt.part == part
+                && attempt.input_hash == input_hash
+                && !matches!(attempt.verdict, Verdict::RateLimited(_))
+        })
+        .collect();
+    if let Some(correct) = judged
+        .iter()
+        .find(|attempt| attempt.verdict == Verdict::Correct)
+    {
+        return Some(if correct.answer == answer {
+            format!("{answer} was already accepted")
+        } else {
+            format!("part {part} was already solved with {}", correct.answer)
+        });
+    }
+    let number = answer.parse::<i128>().ok();
+    judged.iter().find_map(|attempt| {
+        let earlier = attempt.answer.parse::<i128>().ok();
+        match (attempt.verdict, number, earlier) {
+            (verdict, _, _) if attempt.answer == answer => {
+                Some(format!("{answer} was already tried and was {verdict}"))
+            }
+            (Verdict::TooHigh, Some(number), Some(earlier)) if number >= earlier => {
+                Some(format!("{answer} is too high, {earlier} already was"))
+            }
+            (Verdict::TooLow, Some(number), Some(earlier)) if number <= earlier => {
+                Some(format!("{answer} is too low, {earlier} already was"))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// How much longer to wait before sending another answer for the day, at
+/// `time` seconds since the Unix epoch.
+pub fn cooldown_left(attempts: &[Attempt], time: u64) -> Option<Duration> {
+    let last = attempts.iter().max_by_key(|attempt| attempt.time)?;
+    let wait = match last.verdict {
+        Verdict::Correct => return None,
+        Verdict::RateLimited(wait) => wait,
+        _ => COOLDOWN,
+    };
+    let ready = last.time + wait.as_secs();
+    (ready > time).then(|| Duration::from_secs(ready - time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refusal_test() {
+        let attempts = parse(
+            "# day part input-sha256 unix-time verdict answer
+7 1 0123456789abcdef 1000 too-high 5000
+7 1 0123456789abcdef 1070 too-low 1000
+7 1 0123456789abcdef 1140 wrong 3000
+7 1 0123456789abcdef 1150 rate-limited:50 2000
+7 1 fedcba9876543210 1300 correct 77
+7 2 0123456789abcdef 1400 wrong abc",
+        )
+        .unwrap();
+        assert_eq!(
+            attempts[3].verdict,
+            Verdict::RateLimited(Duration::from_secs(50))
+        );
+
+        let refuse = |part, hash, answer| refusal(&attempts, part, hash, answer);
+        let hash = "0123456789abcdef";
+        assert_eq!(
+            refuse(1, hash, "5001").unwrap(),
+            "5001 is too high, 5000 already was"
+        );
+        assert_eq!(
+            refuse(1, hash, "1000").unwrap(),
+            "1000 was already tried and was too-low"
+        );
+        assert_eq!(
+            refuse(1, hash, "999").unwrap(),
+            "999 is too low, 1000 already was"
+        );
+        assert_eq!(
+            refuse(1, hash, "3000").unwrap(),
+            "3000 was already tried and was wrong"
+        );
+        assert_eq!(refuse(1, hash, "2000"), None);
+        assert_eq!(
+            refuse(1, "fedcba9876543210", "77").unwrap(),
+            "77 was already accepted"
+        );
+        assert_eq!(
+            refuse(1, "fedcba9876543210", "78").unwrap(),
+            "part 1 was already solved with 77"
+        );
+        assert_eq!(refuse(2, hash, "abd"), None);
+
+        assert_eq!(
+            cooldown_left(&attempts[..3], 1150),
+            Some(Duration::from_secs(50))
+        );
+        assert_eq!(
+            cooldown_left(&attempts[..4], 1180),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(cooldown_left(&attempts[..4], 1200), None);
+        assert_eq!(cooldown_left(&attempts[..5], 1301), None);
+        assert_eq!(cooldown_left(&[], 0), None);
+        assert!(parse("7 1 0123456789abcdef 1000 maybe 5000").is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use scraper::{ElementRef, Html, Selector};
+use std::io::{self, BufRead, Write};
+
+/// An example input and the answer its test expects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: String,
+}
+
+/// An example block from a puzzle page and every emphasized `<code>` the page
+/// shows after it, the last of which is usually the answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub input: String,
+    pub answers: Vec<String>,
+}
+
+impl Candidate {
+    /// The example with its last answer, if it has any.
+    pub fn default_example(&self) -> Option<Example> {
+        self.answers.last().map(|answer| Example {
+            input: self.input.clone(),
+            answer: answer.clone(),
+        })
+    }
+}
+
+/// Every example of part `part` on the page.
+///
+/// Each `<pre>` block starts an example, unless the previous example hasn't
+/// had an answer yet: blocks in between are usually the example drawn again
+/// partway through, so they're skipped. A part whose description shows answers
+/// before any block of its own gets them paired with the last example of the
+/// part before, which is how part 2 tends to reuse part 1's example.
+pub fn extract(page: &str, part: u8) -> Result<Vec<Candidate>, String> {
+    let html = Html::parse_document(page);
+    let articles = Selector::parse("article.day-desc").unwrap();
+    let blocks_and_answers = Selector::parse("pre, code > em").unwrap();
+    let articles: Vec<ElementRef> = html.select(&articles).collect();
+    if articles.len() < part as usize {
+        return Err(format!("part {part} isn't on the page"));
+    }
+
+    // (article, candidate) for every example up to the requested part.
+    let mut examples: Vec<(usize, Candidate)> = Vec::new();
+    for (article_index, article) in articles.iter().take(part as usize).enumerate() {
+        for element in article.select(&blocks_and_answers) {
+            let text = element.text().collect::<String>();
+            if element.value().name() == "pre" {
+                let starts_example = examples.last().is_none_or(|(index, last)| {
+                    *index != article_index || !last.answers.is_empty()
+                });
+                if starts_example {
+                    let input = text.strip_suffix('\n').unwrap_or(&text).to_owned();
+                    examples.push((
+                        article_index,
+                        Candidate {
+                            input,
+                            answers: Vec::new(),
+                        },
+                    ));
+                }
+            } else if !in_pre(element) {
+                let Some((index, last)) = examples.last() else {
+                    continue;
+                };
+                if *index != article_index {
+                    let input = last.input.clone();
+                    examples.push((
+                        article_index,
+                        Candidate {
+                            input,
+                            answers: Vec::new(),
+                        },
+                    ));
+                }
+                examples.last_mut().unwrap().1.answers.push(text);
+            }
+        }
+    }
+    Ok(examples
+        .into_iter()
+        .filter(|(index, _)| *index == part as usize - 1)
+        .map(|(_, candidate)| candidate)
+        .collect())
+}
+
+fn in_pre(element: ElementRef) -> bool {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| ancestor.value().name() == "pre")
+}
+
+const PREVIEW_LINES: usize = 10;
+
+/// Asks about each candidate in turn: an empty line keeps it with its last
+/// answer, `n` drops it, anything else keeps it with that as the answer.
+/// Running out of input accepts the rest as they are.
+pub fn confirm(
+    candidates: &[Candidate],
+    mut reader: impl BufRead,
+    mut writer: impl Write,
+) -> io::Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    for (i, candidate) in candidates.iter().enumerate() {
+        writeln!(writer, "Example {} of {}:", i + 1, candidates.len())?;
+        let lines: Vec<&str> = candidate.input.lines().collect();
+        for line in lines.iter().take(PREVIEW_LINES) {
+            writeln!(writer, "    {line}")?;
+        }
+        if lines.len() > PREVIEW_LINES {
+            writeln!(writer, "    ... and {} more", lines.len() - PREVIEW_LINES)?;
+        }
+        match candidate.answers.last() {
+            Some(answer) => {
+                writeln!(
+                    writer,
+                    "Emphasized after it: {}",
+                    candidate.answers.join(", ")
+                )?;
+                write!(writer, "Keep it expecting {answer}? [Y/n/other answer] ")?;
+            }
+            None => write!(writer, "No answer found after it. Expected answer? [skip] ")?,
+        }
+        writer.flush()?;
+
+        let mut reply = String::new();
+        let reply = match reader.read_line(&mut reply)? {
+            0 => {
+                writeln!(writer)?;
+                ""
+            }
+            _ => reply.trim(),
+        };
+        let answer = match reply {
+            "" | "y" | "Y" => candidate.answers.last().cloned(),
+            "n" | "N" => None,
+            answer => Some(answer.to_owned()),
+        };
+        if let Some(answer) = answer {
+            examples.push(Example {
+                input: candidate.input.clone(),
+                answer,
+            });
+        }
+    }
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaults(page: &str, part: u8) -> Vec<(String, String)> {
+        extract(page, part)
+            .unwrap()
+            .iter()
+            .filter_map(Candidate::default_example)
+            .map(|example| (example.input, example.answer))
+            .collect()
+    }
+
+    fn first_line(input: &str) -> &str {
+        input.lines().next().unwrap()
+    }
+
+    #[test]
+    fn extract_test() {
+        let day_01 = include_str!("../testdata/pages/day-01.html");
+        let part1 = extract(day_01, 1).unwrap();
+        assert_eq!(part1.len(), 1);
+        assert_eq!(
+            part1[0].input,
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet"
+        );
+        assert_eq!(part1[0].answers, ["12", "38", "15", "77", "142"]);
+        assert_eq!(defaults(day_01, 2)[0].1, "281");
+        assert!(extract(day_01, 3).is_err());
+
+        // The second example's answer comes before it, so it's left for the
+        // user to pair up.
+        let day_08 = include_str!("../testdata/pages/day-08.html");
+        let part1 = extract(day_08, 1).unwrap();
+        assert_eq!(part1.len(), 2);
+        assert_eq!(first_line(&part1[0].input), "RL");
+        assert_eq!(part1[0].answers, ["CCC", "ZZZ", "2", "6"]);
+        assert_eq!(first_line(&part1[1].input), "LLR");
+        assert!(part1[1].answers.is_empty());
+        assert_eq!(defaults(day_08, 2)[0].1, "6");
+
+        // Drawings between an example and its answer are skipped, including a
+        // second example sharing the answer, and the <em>s inside a drawing
+        // aren't taken as answers.
+        let day_10 = include_str!("../testdata/pages/day-10.html");
+        let part2: Vec<_> = defaults(day_10, 2)
+            .into_iter()
+            .map(|(input, answer)| (first_line(&input).to_owned(), answer))
+            .collect();
+        assert_eq!(
+            part2,
+            [
+                ("...........".to_owned(), "4".to_owned()),
+                (".F----7F7F7F7F-7....".to_owned(), "8".to_owned()),
+                ("FF7FSF7F7F7F7F7F---7".to_owned(), "10".to_owned()),
+            ]
+        );
+
+        let day_14 = include_str!("../testdata/pages/day-14.html");
+        let part1 = defaults(day_14, 1);
+        assert_eq!(part1.len(), 1);
+        assert_eq!(
+            (first_line(&part1[0].0), part1[0].1.as_str()),
+            ("O....#....", "136")
+        );
+
+        // Part 2 asks about the same map for several step counts.
+        let day_21 = include_str!("../testdata/pages/day-21.html");
+        let part2 = extract(day_21, 2).unwrap();
+        assert_eq!(part2.len(), 1);
+        assert_eq!(first_line(&part2[0].input), "...........");
+        assert_eq!(
+            part2[0].answers,
+            ["16", "50", "1594", "6536", "167004", "668697", "16733044"]
+        );
+
+        let candidates = [
+            part2[0].clone(),
+            Candidate {
+                input: "a".to_owned(),
+                answers: vec![],
+            },
+            Candidate {
+                input: "b".to_owned(),
+                answers: vec!["1".to_owned()],
+            },
+        ];
+        let mut prompts = Vec::new();
+        let examples = confirm(&candidates, "16\n\nn\n".as_bytes(), &mut prompts).unwrap();
+        assert_eq!(
+            examples,
+            [Example {
+                input: part2[0].input.clone(),
+                answer: "16".to_owned(),
+            }]
+        );
+        let examples = confirm(&candidates, "".as_bytes(), &mut prompts).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert!(String::from_utf8(prompts)
+            .unwrap()
+            .contains("... and 1 more"));
+    }
+}
@@ -0,0 +1,12 @@
+pub mod answers;
+pub mod attempts;
+pub mod examples;
+pub mod params;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod site;
+#[cfg(test)]
+mod stand_in;
+pub mod submit;
+pub mod verify;
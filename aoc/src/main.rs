@@ -0,0 +1,411 @@
+use aoc::{
+    answers::{self, Answer},
+    attempts::{self, Attempt, Verdict},
+    examples::{self, Candidate},
+    params,
+    registry::{self, Registered},
+    runner::{self, Inputs},
+    scaffold,
+    site::{self, PuzzleSource, SavedPage, Site},
+    submit, verify,
+};
+use aoc_common::input::{InputSource, INPUT_DIR_VAR};
+use clap::{Args, Parser, Subcommand};
+use std::{io, path::PathBuf, process::ExitCode, thread};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    /// Puzzle year, the latest one in the workspace if left out
+    #[arg(long, global = true, env = "AOC_YEAR")]
+    year: Option<u16>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single part, both parts of a day, or every solution
+    Run {
+        /// Day to run
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to run, both if left out
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run every registered solution
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        #[command(flatten)]
+        inputs: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Check solutions against the answers recorded in each day's answers.txt
+    Verify {
+        /// Day to check, every day if left out
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to check, both if left out
+        #[arg(requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Record the answer of every part that has none for its input yet
+        #[arg(long, conflicts_with = "param")]
+        record: bool,
+        #[command(flatten)]
+        inputs: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Create a day's crate for part 1, or add part 2 to it, from the puzzle page
+    New {
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to add
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Write a test for every example that has an answer, without asking
+        #[arg(long, short)]
+        yes: bool,
+        #[command(flatten)]
+        source: SourceArgs,
+    },
+    /// List the puzzle values that parts can be given others for
+    Params {
+        /// Day to list, every day if left out
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to list, both if left out
+        #[arg(requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Add a year directory to the workspace for `new` to create days in
+    NewYear {
+        /// Year to add
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+    },
+    /// Submit a part's answer, unless earlier attempts already rule it out
+    Submit {
+        /// Day to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this instead of what the solution gives
+        #[arg(long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        site: SiteArgs,
+        #[command(flatten)]
+        inputs: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+}
+
+#[derive(Args)]
+struct SiteArgs {
+    /// Talk to this server instead of the Advent of Code website
+    #[arg(long, value_name = "URL", env = site::BASE_URL_VAR, default_value = site::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Session cookie of a logged in browser
+    #[arg(long, env = site::COOKIE_VAR, hide_env_values = true)]
+    cookie: Option<String>,
+}
+
+impl SiteArgs {
+    fn into_site(self) -> Site {
+        Site::new(&self.base_url, self.cookie)
+    }
+}
+
+#[derive(Args)]
+struct SourceArgs {
+    #[command(flatten)]
+    site: SiteArgs,
+    /// Read the puzzle page from this saved HTML file instead of fetching it
+    #[arg(long, value_name = "FILE")]
+    page: Option<PathBuf>,
+    /// Read the input from this saved file, together with --page for part 1
+    #[arg(long, value_name = "FILE", requires = "page")]
+    input_file: Option<PathBuf>,
+}
+
+impl SourceArgs {
+    fn into_source(self) -> Box<dyn PuzzleSource> {
+        match self.page {
+            Some(page) => Box::new(SavedPage {
+                page,
+                input: self.input_file,
+            }),
+            None => Box::new(self.site.into_site()),
+        }
+    }
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file instead, or from stdin if `-`
+    #[arg(long, short)]
+    input: Option<String>,
+    /// Read day NN's input from DIR/YYYY/day-NN.txt
+    #[arg(long, value_name = "DIR", env = INPUT_DIR_VAR)]
+    inputs_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn into_inputs(self) -> Inputs {
+        match self.input {
+            Some(arg) => Inputs::Shared(InputSource::from_arg(&arg)),
+            None => Inputs::PerDay(self.inputs_dir),
+        }
+    }
+}
+
+#[derive(Args)]
+struct ParamArgs {
+    /// Use VALUE for the parameter NAME, over the puzzle's value and the
+    /// day's params.txt
+    #[arg(long, value_name = "NAME=VALUE", value_parser = params::parse_override)]
+    param: Vec<(String, String)>,
+}
+
+/// Picks the solutions to run: every year unless one is given, or just the
+/// given or latest year when a day is.
+fn select(
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+) -> Option<Vec<&'static Registered>> {
+    let year = year.or_else(|| day.and(registry::latest_year()));
+    let solutions: Vec<_> = registry::SOLUTIONS
+        .iter()
+        .filter(|solution| year.is_none_or(|year| solution.year == year))
+        .filter(|solution| day.is_none_or(|day| solution.day == day))
+        .filter(|solution| part.is_none_or(|part| solution.part == part))
+        .collect();
+    if solutions.is_empty() {
+        eprintln!("no solution registered for that day and part");
+        None
+    } else {
+        Some(solutions)
+    }
+}
+
+/// Works out the answer, checks it against the day's attempt log, waits out
+/// any cooldown, then submits it and logs what the site said.
+fn submit(
+    solution: &Registered,
+    answer: Option<String>,
+    site: &Site,
+    inputs: &Inputs,
+    overrides: &[(String, String)],
+) -> Result<Verdict, String> {
+    let (year, day, part) = (solution.year, solution.day, solution.part);
+    let input = inputs.read(year, day)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            runner::install_panic_hook();
+            runner::run_with_input(solution, &input, overrides).answer?
+        }
+    };
+    let input_hash = answers::input_hash(&input);
+    let logged = attempts::load(year, day)?;
+    if let Some(reason) = attempts::refusal(&logged, part, &input_hash, &answer) {
+        return Err(format!("not submitting: {reason}"));
+    }
+    if let Some(wait) = attempts::cooldown_left(&logged, attempts::now()) {
+        println!("waiting {}s before submitting", wait.as_secs());
+        thread::sleep(wait);
+    }
+
+    println!("submitting {answer} for day {day} part {part}");
+    let verdict = submit::submit(site, year, day, part, &answer)?;
+    let attempt = Attempt {
+        day,
+        part,
+        input_hash: input_hash.clone(),
+        time: attempts::now(),
+        verdict,
+        answer: answer.clone(),
+    };
+    attempts::record(year, &attempt).map_err(|err| format!("couldn't log the attempt: {err}"))?;
+    if verdict == Verdict::Correct {
+        let recorded = answers::load(year, day)?;
+        let is_recorded = recorded
+            .iter()
+            .any(|known| known.part == part && known.input_hash == input_hash);
+        if !is_recorded {
+            let answer = Answer {
+                day,
+                part,
+                input_hash,
+                answer,
+            };
+            answers::record(year, &answer)
+                .map_err(|err| format!("couldn't record the answer: {err}"))?;
+        }
+    }
+    Ok(verdict)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            inputs,
+            params,
+            ..
+        } => {
+            let Some(solutions) = select(cli.year, day, part) else {
+                return ExitCode::FAILURE;
+            };
+            if let Err(err) = params::check_overrides(&solutions, &params.param) {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            runner::install_panic_hook();
+            let outcomes = runner::run_all(&solutions, &inputs.into_inputs(), &params.param);
+            runner::print_table(&outcomes);
+            if outcomes.iter().all(|outcome| outcome.answer.is_ok()) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Verify {
+            day,
+            part,
+            record,
+            inputs,
+            params,
+        } => {
+            let Some(solutions) = select(cli.year, day, part) else {
+                return ExitCode::FAILURE;
+            };
+            if let Err(err) = params::check_overrides(&solutions, &params.param) {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            runner::install_panic_hook();
+            let verifications = verify::verify(&solutions, &inputs.into_inputs(), &params.param);
+            verify::print_table(&verifications);
+            if record {
+                match verify::record_unrecorded(&verifications) {
+                    Ok(count) => println!("recorded {count} new answers"),
+                    Err(err) => {
+                        eprintln!("couldn't record answers: {err}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            if verifications.iter().any(verify::Verification::is_failure) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Command::New {
+            day,
+            part,
+            yes,
+            source,
+        } => {
+            let root = runner::workspace_dir();
+            let Some(year) = cli.year.or_else(|| runner::years().last().copied()) else {
+                eprintln!("no year directory, run `aoc new-year` first");
+                return ExitCode::FAILURE;
+            };
+            let source = source.into_source();
+            let choose = |candidates: Vec<Candidate>| {
+                if yes {
+                    Ok(candidates
+                        .iter()
+                        .filter_map(Candidate::default_example)
+                        .collect())
+                } else {
+                    examples::confirm(&candidates, io::stdin().lock(), io::stdout())
+                        .map_err(|err| err.to_string())
+                }
+            };
+            match scaffold::new_part(&root, source.as_ref(), year, day, part, choose) {
+                Ok(created) => {
+                    for path in created {
+                        println!("created {}", path.display());
+                    }
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("couldn't create day {day} part {part}: {err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Params { day, part } => {
+            let Some(solutions) = select(cli.year, day, part) else {
+                return ExitCode::FAILURE;
+            };
+            params::print_table(&solutions);
+            ExitCode::SUCCESS
+        }
+        Command::NewYear { year } => match scaffold::new_year(&runner::workspace_dir(), year) {
+            Ok(created) => {
+                println!("created {}", created.display());
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("couldn't add {year}: {err}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Submit {
+            day,
+            part,
+            answer,
+            site,
+            inputs,
+            params,
+        } => {
+            let year = cli.year.or_else(registry::latest_year).unwrap_or_default();
+            let Some(solution) = registry::find(year, day, part) else {
+                eprintln!("no solution registered for that day and part");
+                return ExitCode::FAILURE;
+            };
+            if let Err(err) = params::check_overrides(&[solution], &params.param) {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            let site = site.into_site();
+            match submit(
+                solution,
+                answer,
+                &site,
+                &inputs.into_inputs(),
+                &params.param,
+            ) {
+                Ok(Verdict::Correct) => {
+                    println!("correct");
+                    ExitCode::SUCCESS
+                }
+                Ok(Verdict::RateLimited(wait)) => {
+                    println!("rate limited, try again in {}s", wait.as_secs());
+                    ExitCode::FAILURE
+                }
+                Ok(verdict) => {
+                    println!("{}", verdict.to_string().replace('-', " "));
+                    ExitCode::FAILURE
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
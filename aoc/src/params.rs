@@ -0,0 +1,191 @@
+use crate::{registry::Registered, runner::day_dir};
+use aoc_common::{Param, Params};
+use std::{fs, io::ErrorKind, path::PathBuf};
+
+/// A value a day's `params.txt` gives one of its parts' parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    pub part: u8,
+    pub name: String,
+    pub value: String,
+}
+
+pub fn params_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("params.txt")
+}
+
+pub fn parse(text: &str) -> Result<Vec<Setting>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let part = fields.next().and_then(|part| part.parse().ok());
+            let name = fields.next().filter(|name| !name.is_empty());
+            let value = fields
+                .next()
+                .map(str::trim)
+                .filter(|value| !value.is_empty());
+            match (part, name, value) {
+                (Some(part), Some(name), Some(value)) => Ok(Setting {
+                    part,
+                    name: name.to_owned(),
+                    value: value.to_owned(),
+                }),
+                _ => Err(format!("line {}: expected `part name value`", i + 1)),
+            }
+        })
+        .collect()
+}
+
+/// Every setting for the day, or none if it has no params file.
+pub fn load(year: u16, day: u8) -> Result<Vec<Setting>, String> {
+    let path = params_path(year, day);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("{}: {err}", path.display())),
+    }
+}
+
+/// A `NAME=VALUE` given on the command line.
+pub fn parse_override(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+        _ => Err(format!("expected NAME=VALUE, not {arg}")),
+    }
+}
+
+/// Refuses an override that none of `solutions` takes, which is most likely
+/// a typo.
+pub fn check_overrides(
+    solutions: &[&Registered],
+    overrides: &[(String, String)],
+) -> Result<(), String> {
+    for (name, _) in overrides {
+        let is_declared = solutions
+            .iter()
+            .flat_map(|solution| solution.params)
+            .any(|param| param.name == name);
+        if !is_declared {
+            return Err(format!("no part being run takes a parameter called {name}"));
+        }
+    }
+    Ok(())
+}
+
+/// What `solution` runs with: its defaults, then whatever the day's
+/// `params.txt` sets for its part, then those of `overrides` it takes.
+pub fn resolve(solution: &Registered, overrides: &[(String, String)]) -> Result<Params, String> {
+    let mut params = Params::defaults(solution.params);
+    let path = params_path(solution.year, solution.day);
+    for setting in load(solution.year, solution.day)? {
+        if setting.part == solution.part {
+            params
+                .set(&setting.name, setting.value)
+                .map_err(|err| format!("{}: part {}: {err}", path.display(), setting.part))?;
+        }
+    }
+    for (name, value) in overrides {
+        if solution.params.iter().any(|param| param.name == name) {
+            params
+                .set(name, value.clone())
+                .map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(params)
+}
+
+/// Lists every parameter of `solutions` with the value it runs with unless
+/// one is given on the command line.
+pub fn print_table(solutions: &[&Registered]) {
+    let rows: Vec<(&Registered, &Param, String)> = solutions
+        .iter()
+        .flat_map(|&solution| {
+            let params = resolve(solution, &[]);
+            solution.params.iter().map(move |param| {
+                let value = match &params {
+                    Ok(params) => params
+                        .get::<String>(param.name)
+                        .unwrap_or_else(|err| err.to_string()),
+                    Err(err) => err.clone(),
+                };
+                (solution, param, value)
+            })
+        })
+        .collect();
+    if rows.is_empty() {
+        println!("no parameters");
+        return;
+    }
+    let name_width = rows
+        .iter()
+        .map(|(_, param, _)| param.name.len())
+        .chain(std::iter::once("Name".len()))
+        .max()
+        .unwrap();
+    let value_width = rows
+        .iter()
+        .map(|(_, _, value)| value.chars().count())
+        .chain(std::iter::once("Value".len()))
+        .max()
+        .unwrap();
+    let default_width = rows
+        .iter()
+        .map(|(_, param, _)| param.default.len())
+        .chain(std::iter::once("Default".len()))
+        .max()
+        .unwrap();
+    println!(
+        "Year  Day  Part  {:<name_width$}  {:<value_width$}  {:<default_width$}  About",
+        "Name", "Value", "Default"
+    );
+    for (solution, param, value) in rows {
+        println!(
+            "{}  {:>3}  {:>4}  {:<name_width$}  {:<value_width$}  {:<default_width$}  {}",
+            solution.year,
+            solution.day,
+            solution.part,
+            param.name,
+            value,
+            param.default,
+            param.about
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let settings = parse(
+            "# part name value
+1 steps 6
+
+2 expansion 100
+1 bag 12 red, 13 green, 14 blue ",
+        )
+        .unwrap();
+        assert_eq!(settings.len(), 3);
+        assert_eq!(
+            settings[1],
+            Setting {
+                part: 2,
+                name: "expansion".to_owned(),
+                value: "100".to_owned(),
+            }
+        );
+        assert_eq!(settings[2].value, "12 red, 13 green, 14 blue");
+        assert_eq!(
+            parse("1 steps").unwrap_err(),
+            "line 1: expected `part name value`"
+        );
+        assert_eq!(
+            parse_override("steps=6"),
+            Ok(("steps".to_owned(), "6".to_owned()))
+        );
+        assert!(parse_override("steps").is_err());
+    }
+}
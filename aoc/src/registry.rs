@@ -0,0 +1,176 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_common::{Param, Params, Solution};
+
+pub struct Registered {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub example: Option<&'static str>,
+    /// Values from the puzzle text the part can be given others for.
+    pub params: &'static [Param],
+    /// The answer with the given parameters, or why the input was refused.
+    pub run: fn(&str, &Params) -> Result<String, String>,
+    /// Total time spent in `parse` over the given number of iterations.
+    pub time_parse: fn(&str, u64) -> Duration,
+    /// Total time spent in `solve` over the given number of iterations. Each
+    /// iteration parses afresh outside the timed section; an input that
+    /// doesn't parse isn't timed at all.
+    pub time_solve: fn(&str, &Params, u64) -> Duration,
+}
+
+fn run<S: Solution>(input: &str, params: &Params) -> Result<String, String> {
+    S::run(input, params)
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+fn time_parse<S: Solution>(input: &str, iterations: u64) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let _ = black_box(S::parse(black_box(input)));
+    }
+    start.elapsed()
+}
+
+fn time_solve<S: Solution>(input: &str, params: &Params, iterations: u64) -> Duration {
+    let mut total = Duration::ZERO;
+    for _ in 0..iterations {
+        let Ok(parsed) = S::parse(input) else {
+            break;
+        };
+        let start = Instant::now();
+        let _ = black_box(S::solve(black_box(parsed), params));
+        total += start.elapsed();
+    }
+    total
+}
+
+const fn register<S: Solution>() -> Registered {
+    Registered {
+        year: S::YEAR,
+        day: S::DAY,
+        part: S::PART,
+        example: S::EXAMPLE,
+        params: S::PARAMS,
+        run: run::<S>,
+        time_parse: time_parse::<S>,
+        time_solve: time_solve::<S>,
+    }
+}
+
+pub const SOLUTIONS: &[Registered] = &[
+    register::<day_2023_01::part1::Part1>(),
+    register::<day_2023_01::part2::Part2>(),
+    register::<day_2023_02::part1::Part1>(),
+    register::<day_2023_02::part2::Part2>(),
+    register::<day_2023_03::part1::Part1>(),
+    register::<day_2023_03::part2::Part2>(),
+    register::<day_2023_04::part1::Part1>(),
+    register::<day_2023_04::part2::Part2>(),
+    register::<day_2023_05::part1::Part1>(),
+    register::<day_2023_05::part2::Part2>(),
+    register::<day_2023_06::part1::Part1>(),
+    register::<day_2023_06::part2::Part2>(),
+    register::<day_2023_07::part1::Part1>(),
+    register::<day_2023_07::part2::Part2>(),
+    register::<day_2023_08::part1::Part1>(),
+    register::<day_2023_08::part2::Part2>(),
+    register::<day_2023_09::part1::Part1>(),
+    register::<day_2023_09::part2::Part2>(),
+    register::<day_2023_10::part1::Part1>(),
+    register::<day_2023_10::part2::Part2>(),
+    register::<day_2023_11::part1::Part1>(),
+    register::<day_2023_11::part2::Part2>(),
+    register::<day_2023_12::part1::Part1>(),
+    register::<day_2023_12::part2::Part2>(),
+    register::<day_2023_13::part1::Part1>(),
+    register::<day_2023_13::part2::Part2>(),
+    register::<day_2023_14::part1::Part1>(),
+    register::<day_2023_14::part2::Part2>(),
+    register::<day_2023_15::part1::Part1>(),
+    register::<day_2023_15::part2::Part2>(),
+    register::<day_2023_16::part1::Part1>(),
+    register::<day_2023_16::part2::Part2>(),
+    register::<day_2023_17::part1::Part1>(),
+    register::<day_2023_17::part2::Part2>(),
+    register::<day_2023_18::part1::Part1>(),
+    register::<day_2023_18::part2::Part2>(),
+    register::<day_2023_19::part1::Part1>(),
+    register::<day_2023_19::part2::Part2>(),
+    register::<day_2023_20::part1::Part1>(),
+    register::<day_2023_20::part2::Part2>(),
+    register::<day_2023_21::part1::Part1>(),
+    register::<day_2023_21::part2::Part2>(),
+    register::<day_2023_22::part1::Part1>(),
+    register::<day_2023_22::part2::Part2>(),
+    register::<day_2023_23::part1::Part1>(),
+    register::<day_2023_23::part2::Part2>(),
+    register::<day_2023_24::part1::Part1>(),
+    register::<day_2023_24::part2::Part2>(),
+    register::<day_2023_25::part1::Part1>(),
+];
+
+/// The most recent year with a registered solution.
+pub fn latest_year() -> Option<u16> {
+    SOLUTIONS.iter().map(|solution| solution.year).max()
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Registered> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day && solution.part == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_test() {
+        assert_eq!(SOLUTIONS.len(), 49);
+        for (i, solution) in SOLUTIONS.iter().enumerate() {
+            assert_eq!(solution.year, 2023);
+            assert_eq!(solution.day as usize, i / 2 + 1);
+            assert_eq!(solution.part as usize, i % 2 + 1);
+        }
+        let day_01_part1 = find(2023, 1, 1).unwrap();
+        assert_eq!(
+            (day_01_part1.run)("1abc2\npqr3stu8vwx", &Params::defaults(day_01_part1.params)),
+            Ok("50".to_owned())
+        );
+        let day_11_part2 = find(2023, 11, 2).unwrap();
+        let expansion = Params::new(
+            day_11_part2.params,
+            &[("expansion".to_owned(), "10".to_owned())],
+        )
+        .unwrap();
+        assert_eq!(
+            (day_11_part2.run)("#..\n...\n..#", &expansion),
+            Ok("22".to_owned())
+        );
+        assert!(find(2023, 25, 2).is_none());
+        assert!(find(2024, 1, 1).is_none());
+    }
+
+    #[test]
+    fn registry_examples_test() {
+        // A part only belongs in the registry once it's solved, so every
+        // example has to run through without panicking.
+        for solution in SOLUTIONS {
+            let Some(example) = solution.example else {
+                continue;
+            };
+            let params = Params::defaults(solution.params);
+            let ran = std::panic::catch_unwind(|| (solution.run)(example, &params));
+            assert!(
+                ran.is_ok(),
+                "{} day {} part {} panics on its example",
+                solution.year,
+                solution.day,
+                solution.part
+            );
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{params, registry::Registered};
+use aoc_common::input::InputSource;
+use std::{
+    any::Any,
+    cell::RefCell,
+    fs, panic,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replaces the default panic hook, which would print a message and possibly a
+/// backtrace in the middle of the table, with one that just remembers where the
+/// panic happened so `run_with_input` can report it.
+pub fn install_panic_hook() {
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(|location| location.to_string());
+        PANIC_LOCATION.with(|last| *last.borrow_mut() = location);
+    }));
+}
+
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    pub fn failed(solution: &Registered, err: String) -> Self {
+        Outcome {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            answer: Err(err),
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+/// Where `run_all` reads each day's input from.
+pub enum Inputs {
+    /// Each day's own input, or `YYYY/day-NN.txt` in the given directory.
+    PerDay(Option<PathBuf>),
+    /// One input shared by everything being run.
+    Shared(InputSource),
+}
+
+impl Inputs {
+    fn source(&self, year: u16, day: u8) -> InputSource {
+        match self {
+            Inputs::PerDay(Some(dir)) => InputSource::in_dir(dir, year, day),
+            Inputs::PerDay(None) => InputSource::default_for(&day_dir(year, day)),
+            Inputs::Shared(source) => source.clone(),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, String> {
+        self.source(year, day)
+            .read()
+            .map_err(|err| format!("couldn't read input: {err}"))
+    }
+}
+
+/// The directory of the workspace this binary was built from.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+pub fn year_dir(year: u16) -> PathBuf {
+    workspace_dir().join(year.to_string())
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day-{day:02}"))
+}
+
+/// Every year with a directory in the workspace, oldest first.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = fs::read_dir(workspace_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .filter(|year| (2015..10000).contains(year))
+        .collect();
+    years.sort_unstable();
+    years
+}
+
+/// Runs every solution in order, reading each day's input only once.
+pub fn run_all(
+    solutions: &[&Registered],
+    inputs: &Inputs,
+    overrides: &[(String, String)],
+) -> Vec<Outcome> {
+    solutions
+        .chunk_by(|a, b| (a.year, a.day) == (b.year, b.day))
+        .flat_map(|day_solutions| {
+            let input = inputs.read(day_solutions[0].year, day_solutions[0].day);
+            day_solutions
+                .iter()
+                .map(|solution| match &input {
+                    Ok(input) => run_with_input(solution, input, overrides),
+                    Err(err) => Outcome::failed(solution, err.clone()),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Runs `solution` with its parameters resolved as `params::resolve` does.
+pub fn run_with_input(
+    solution: &Registered,
+    input: &str,
+    overrides: &[(String, String)],
+) -> Outcome {
+    let params = match params::resolve(solution, overrides) {
+        Ok(params) => params,
+        Err(err) => return Outcome::failed(solution, err),
+    };
+    let start = Instant::now();
+    let answer = panic::catch_unwind(|| (solution.run)(input, &params))
+        .map_err(|payload| {
+            let message = panic_message(payload.as_ref());
+            match PANIC_LOCATION.with(|last| last.borrow_mut().take()) {
+                Some(location) => format!("panicked at {location}: {message}"),
+                None => format!("panicked: {message}"),
+            }
+        })
+        .and_then(|answer| answer.map_err(|err| format!("error: {err}")));
+    Outcome {
+        year: solution.year,
+        day: solution.day,
+        part: solution.part,
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic payload"
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{secs:.2}s")
+    } else if secs >= 1e-3 {
+        format!("{:.2}ms", secs * 1e3)
+    } else {
+        format!("{:.2}µs", secs * 1e6)
+    }
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    let cells: Vec<String> = outcomes
+        .iter()
+        .map(|outcome| match &outcome.answer {
+            Ok(answer) => answer.clone(),
+            Err(err) => err.clone(),
+        })
+        .collect();
+    let answer_width = cells
+        .iter()
+        .map(|cell| cell.chars().count())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap();
+    println!(
+        "Year  Day  Part  {:<answer_width$}  {:>10}",
+        "Answer", "Time"
+    );
+    for (outcome, cell) in outcomes.iter().zip(cells) {
+        println!(
+            "{}  {:>3}  {:>4}  {:<answer_width$}  {:>10}",
+            outcome.year,
+            outcome.day,
+            outcome.part,
+            cell,
+            format_duration(outcome.elapsed)
+        );
+    }
+    if outcomes.len() > 1 {
+        let total: Duration = outcomes.iter().map(|outcome| outcome.elapsed).sum();
+        println!(
+            "{:>15}  {:<answer_width$}  {:>10}",
+            "",
+            "Total",
+            format_duration(total)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_with_input_test() {
+        let ok = Registered {
+            year: 2023,
+            day: 1,
+            part: 1,
+            example: None,
+            params: &[],
+            run: |input, _| Ok(input.len().to_string()),
+            time_parse: |_, _| Duration::ZERO,
+            time_solve: |_, _, _| Duration::ZERO,
+        };
+        assert_eq!(run_with_input(&ok, "abc", &[]).answer, Ok("3".to_owned()));
+
+        let panics = Registered {
+            year: 2023,
+            day: 1,
+            part: 2,
+            example: None,
+            params: &[],
+            run: |_, _| unimplemented!(),
+            time_parse: |_, _| Duration::ZERO,
+            time_solve: |_, _, _| Duration::ZERO,
+        };
+        assert_eq!(
+            run_with_input(&panics, "", &[]).answer,
+            Err("panicked: not implemented".to_owned())
+        );
+
+        let refuses = Registered {
+            year: 2023,
+            day: 1,
+            part: 2,
+            example: None,
+            params: &[],
+            run: |_, _| Err("1:1: expected a digit".to_owned()),
+            time_parse: |_, _| Duration::ZERO,
+            time_solve: |_, _, _| Duration::ZERO,
+        };
+        assert_eq!(
+            run_with_input(&refuses, "", &[]).answer,
+            Err("error: 1:1: expected a digit".to_owned())
+        );
+    }
+
+    #[test]
+    fn format_duration_test() {
+        assert_eq!(format_duration(Duration::from_micros(250)), "250.00µs");
+        assert_eq!(format_duration(Duration::from_millis(12)), "12.00ms");
+        assert_eq!(format_duration(Duration::from_millis(3500)), "3.50s");
+    }
+}
@@ -0,0 +1,441 @@
+use crate::{
+    examples::{self, Candidate, Example},
+    site::PuzzleSource,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = include_str!("../../day_template.rs");
+
+/// A new day's `lib.rs`, with the error its parts refuse an input with. The
+/// day adds variants for whatever else its puzzle can trip over.
+const LIB: &str = "\
+pub mod part1;
+
+use aoc_common::parse::ParseError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+";
+
+/// `text` as the body of a string literal that starts with `"\` and a newline,
+/// which would otherwise swallow the first line's indentation.
+fn escape_literal(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('"', "\\\"");
+    match escaped.chars().next() {
+        Some(' ') => format!("\\x20{}", &escaped[1..]),
+        Some('\t') => format!("\\t{}", &escaped[1..]),
+        _ => escaped,
+    }
+}
+
+fn crate_name(year: u16, day: u8) -> String {
+    format!("day-{year}-{day:02}")
+}
+
+fn bin_source(year: u16, day: u8, part: u8) -> String {
+    format!(
+        "\
+fn main() -> Result<(), Box<dyn std::error::Error>> {{
+    let input = aoc_common::input!()?;
+    let output = day_{year}_{day:02}::part{part}::part{part}(&input)?;
+    dbg!(output);
+    Ok(())
+}}
+"
+    )
+}
+
+/// The day's manifest, with only part 1's binary until part 2 is added.
+fn manifest(year: u16, day: u8) -> String {
+    let name = crate_name(year, day);
+    format!(
+        "\
+[package]
+name = \"{name}\"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common.workspace = true
+{}",
+        bin_target(year, day, 1)
+    )
+}
+
+/// The manifest's `[[bin]]` section for a part, after a blank line.
+fn bin_target(year: u16, day: u8, part: u8) -> String {
+    let name = crate_name(year, day);
+    format!(
+        "
+[[bin]]
+name = \"{name}-part{part}\"
+path = \"src/bin/part{part}.rs\"
+"
+    )
+}
+
+/// The template's tail: the example constant for the first example and a test
+/// for each one.
+fn examples_source(part: u8, examples: &[Example]) -> String {
+    let Some((first, rest)) = examples.split_first() else {
+        return String::new();
+    };
+    let tail = &TEMPLATE[TEMPLATE.find("const EXAMPLE: &str").unwrap()..];
+    let mut tail = tail
+        .replace("part1", &format!("part{part}"))
+        .replace("TEST_CASE", &escape_literal(&first.input))
+        .replace("TEST_RESULT", &first.answer);
+    let mut extra_tests = String::new();
+    for (i, example) in rest.iter().enumerate() {
+        let n = i + 2;
+        extra_tests += &format!(
+            "
+    const EXAMPLE_{n}: &str = \"\\
+{}\";
+
+    #[test]
+    fn part{part}_example_{n}_test() {{
+        let result = part{part}(EXAMPLE_{n});
+        assert_eq!(result, Ok({}));
+    }}
+",
+            escape_literal(&example.input),
+            example.answer
+        );
+    }
+    let end_of_tests = tail.trim_end().len() - 1;
+    tail.insert_str(end_of_tests, &extra_tests);
+    tail
+}
+
+/// `source` up to its example constant, with the `Solution` impl's `EXAMPLE`
+/// pointing at nothing if there are no examples to follow.
+fn head(source: &str, examples: &[Example]) -> String {
+    let end = source
+        .find("const EXAMPLE: &str")
+        .or_else(|| source.find("#[cfg(test)]"))
+        .unwrap_or(source.len());
+    let head = &source[..end];
+    if examples.is_empty() {
+        head.trim_end().replace("Some(EXAMPLE)", "None") + "\n"
+    } else {
+        head.to_owned()
+    }
+}
+
+/// Adds the part to the `aoc` runner's dependencies and registry.
+fn register(root: &Path, year: u16, day: u8, part: u8) -> Result<(), String> {
+    if part == 1 {
+        let path = root.join("aoc/Cargo.toml");
+        let manifest = read(&path)?;
+        let dependency = format!(
+            "{} = {{ path = \"../{year}/day-{day:02}\" }}\n",
+            crate_name(year, day)
+        );
+        let at = match manifest.rfind("\nday-") {
+            Some(last) => last + 1 + manifest[last + 1..].find('\n').map_or(0, |end| end + 1),
+            None => {
+                let section = "[dependencies]\n";
+                manifest
+                    .find(section)
+                    .ok_or_else(|| format!("{}: no [dependencies] section", path.display()))?
+                    + section.len()
+            }
+        };
+        let mut manifest = manifest;
+        manifest.insert_str(at, &dependency);
+        write(&path, &manifest)?;
+    }
+
+    let path = root.join("aoc/src/registry.rs");
+    let registry = read(&path)?;
+    let start = registry
+        .find("pub const SOLUTIONS")
+        .ok_or_else(|| format!("{}: no SOLUTIONS to register in", path.display()))?;
+    let end = start
+        + registry[start..]
+            .find("\n];")
+            .ok_or_else(|| format!("{}: unterminated SOLUTIONS", path.display()))?
+        + 1;
+    let mut registry = registry;
+    registry.insert_str(
+        end,
+        &format!("    register::<day_{year}_{day:02}::part{part}::Part{part}>(),\n"),
+    );
+    write(&path, &registry)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Creates `YYYY/day-NN` under `root` for part 1, or adds part 2 to it, and
+/// registers the new part with the runner. `choose` gets every example found
+/// on the puzzle page and picks the ones to write a test for. Returns the
+/// files it created.
+pub fn new_part(
+    root: &Path,
+    source: &dyn PuzzleSource,
+    year: u16,
+    day: u8,
+    part: u8,
+    choose: impl FnOnce(Vec<Candidate>) -> Result<Vec<Example>, String>,
+) -> Result<Vec<PathBuf>, String> {
+    let year_dir = root.join(year.to_string());
+    if !year_dir.is_dir() {
+        return Err(format!(
+            "no {year} directory, run `aoc new-year {year}` first"
+        ));
+    }
+    let day_dir = year_dir.join(format!("day-{day:02}"));
+    let part_path = day_dir.join(format!("src/part{part}.rs"));
+    let bin_path = day_dir.join(format!("src/bin/part{part}.rs"));
+    let lib_path = day_dir.join("src/lib.rs");
+
+    let created = if part == 1 {
+        if day_dir.exists() {
+            return Err(format!("{} already exists", day_dir.display()));
+        }
+        let page = source.page(year, day)?;
+        let input = source.input(year, day)?;
+        let examples = choose(examples::extract(&page, part)?)?;
+        let input_path = day_dir.join("src/bin/input.txt");
+        let manifest_path = day_dir.join("Cargo.toml");
+        fs::create_dir_all(day_dir.join("src/bin"))
+            .map_err(|err| format!("{}: {err}", day_dir.display()))?;
+        write(&manifest_path, &manifest(year, day))?;
+        write(&lib_path, LIB)?;
+        let template = TEMPLATE
+            .replace("YEAR_NUMBER", &year.to_string())
+            .replace("DAY_NUMBER", &day.to_string());
+        write(
+            &part_path,
+            &(head(&template, &examples) + &examples_source(1, &examples)),
+        )?;
+        write(&bin_path, &bin_source(year, day, 1))?;
+        write(&input_path, input.strip_suffix('\n').unwrap_or(&input))?;
+        vec![manifest_path, lib_path, part_path, bin_path, input_path]
+    } else {
+        if part_path.exists() {
+            return Err(format!("{} already exists", part_path.display()));
+        }
+        let part1 = read(&day_dir.join("src/part1.rs"))?;
+        let examples = choose(examples::extract(&source.page(year, day)?, part)?)?;
+        let part2 = head(&part1, &examples)
+            .replace("part1", "part2")
+            .replace("Part1", "Part2")
+            .replace("const PART: u8 = 1;", "const PART: u8 = 2;");
+        write(&part_path, &(part2 + &examples_source(2, &examples)))?;
+        let lib =
+            read(&lib_path)?.replacen("pub mod part1;\n", "pub mod part1;\npub mod part2;\n", 1);
+        write(&lib_path, &lib)?;
+        write(&bin_path, &bin_source(year, day, 2))?;
+        let manifest_path = day_dir.join("Cargo.toml");
+        let manifest = read(&manifest_path)? + &bin_target(year, day, 2);
+        write(&manifest_path, &manifest)?;
+        vec![part_path, bin_path, manifest_path]
+    };
+    register(root, year, day, part)?;
+    Ok(created)
+}
+
+/// Adds `YYYY/day-*` to the workspace members and creates the directory for
+/// `new_part` to put the year's days in.
+pub fn new_year(root: &Path, year: u16) -> Result<PathBuf, String> {
+    let year_dir = root.join(year.to_string());
+    if year_dir.exists() {
+        return Err(format!("{} already exists", year_dir.display()));
+    }
+    let path = root.join("Cargo.toml");
+    let manifest = read(&path)?;
+    let start = manifest
+        .find("members = [")
+        .ok_or_else(|| format!("{}: no workspace members", path.display()))?
+        + "members = [".len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or_else(|| format!("{}: unterminated workspace members", path.display()))?;
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|member| !member.is_empty())
+        .collect();
+    let member = format!("\"{year}/day-*\"");
+    members.push(&member);
+    let manifest = format!(
+        "{}{}{}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    );
+    fs::create_dir(&year_dir).map_err(|err| format!("{}: {err}", year_dir.display()))?;
+    write(&path, &manifest)?;
+    Ok(year_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::SavedPage;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 16: The Floor Will Be Lava ---</h2>
+<p>For example:</p>
+<pre><code>.|...\....
+|.-.\.....
+</code></pre>
+<p>In this example, <code><em>46</em></code> tiles become <em>energized</em>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using this configuration, <code><em>51</em></code> tiles are energized.</p>
+<p>Or with a mirror in front:</p>
+<pre><code> /..
+"..
+</code></pre>
+<p>Here it's <code><em>7</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    fn defaults(candidates: Vec<Candidate>) -> Result<Vec<Example>, String> {
+        Ok(candidates
+            .iter()
+            .filter_map(Candidate::default_example)
+            .collect())
+    }
+
+    #[test]
+    fn new_part_test() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-test-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"2023/day-*\"]\n",
+        )
+        .unwrap();
+        fs::write(root.join("page.html"), PAGE).unwrap();
+        fs::write(root.join("input.txt"), "..\\\n").unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nday-2023-25 = { path = \"../2023/day-25\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "pub const SOLUTIONS: &[Registered] = &[\n];\n",
+        )
+        .unwrap();
+        let source = SavedPage {
+            page: root.join("page.html"),
+            input: Some(root.join("input.txt")),
+        };
+
+        let no_year = new_part(&root, &source, 2024, 16, 1, defaults);
+        new_year(&root, 2024).unwrap();
+        let year_again = new_year(&root, 2024);
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        new_part(&root, &source, 2024, 16, 1, defaults).unwrap();
+        let part1_manifest = read("2024/day-16/Cargo.toml");
+        new_part(&root, &source, 2024, 16, 2, defaults).unwrap();
+        let workspace = read("Cargo.toml");
+        let day_manifest = read("2024/day-16/Cargo.toml");
+        let part1 = read("2024/day-16/src/part1.rs");
+        let part2 = read("2024/day-16/src/part2.rs");
+        let lib = read("2024/day-16/src/lib.rs");
+        let bin = read("2024/day-16/src/bin/part2.rs");
+        let input = read("2024/day-16/src/bin/input.txt");
+        let manifest = read("aoc/Cargo.toml");
+        let registry = read("aoc/src/registry.rs");
+        let again = new_part(&root, &source, 2024, 16, 1, defaults);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            no_year.unwrap_err(),
+            "no 2024 directory, run `aoc new-year 2024` first"
+        );
+        assert!(year_again.unwrap_err().ends_with("already exists"));
+        assert_eq!(
+            workspace,
+            "[workspace]\nmembers = [\"aoc\", \"2023/day-*\", \"2024/day-*\"]\n"
+        );
+        assert!(part1_manifest
+            .ends_with("[[bin]]\nname = \"day-2024-16-part1\"\npath = \"src/bin/part1.rs\"\n"));
+        assert!(!part1_manifest.contains("part2"));
+        assert_eq!(
+            day_manifest,
+            part1_manifest
+                + "\n[[bin]]\nname = \"day-2024-16-part2\"\npath = \"src/bin/part2.rs\"\n"
+        );
+        assert!(part1.contains("const YEAR: u16 = 2024;\n    const DAY: u8 = 16;"));
+        assert!(part1.contains("const EXAMPLE: &str = \"\\\n.|...\\\\....\n|.-.\\\\.....\";"));
+        assert!(part1.contains("assert_eq!(result, Ok(46));"));
+        assert!(part2.contains("const PART: u8 = 2;"));
+        assert!(part2.ends_with(
+            r#"const EXAMPLE: &str = "\
+.|...\\....
+|.-.\\.....";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_test() {
+        let result = part2(EXAMPLE);
+        assert_eq!(result, Ok(51));
+    }
+
+    const EXAMPLE_2: &str = "\
+\x20/..
+\"..";
+
+    #[test]
+    fn part2_example_2_test() {
+        let result = part2(EXAMPLE_2);
+        assert_eq!(result, Ok(7));
+    }
+}
+"#
+        ));
+        assert!(lib.starts_with("pub mod part1;\npub mod part2;\n\n"));
+        assert!(lib.contains("pub enum Error {"));
+        assert!(bin.contains("day_2024_16::part2::part2(&input)?"));
+        assert_eq!(input, "..\\");
+        assert!(manifest.ends_with(
+            "day-2023-25 = { path = \"../2023/day-25\" }\nday-2024-16 = { path = \"../2024/day-16\" }\n"
+        ));
+        assert_eq!(
+            registry,
+            "pub const SOLUTIONS: &[Registered] = &[\n    \
+             register::<day_2024_16::part1::Part1>(),\n    \
+             register::<day_2024_16::part2::Part2>(),\n];\n"
+        );
+        assert!(again.unwrap_err().ends_with("already exists"));
+    }
+}
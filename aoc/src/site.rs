@@ -0,0 +1,109 @@
+use std::{fs, path::PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Points every request at another server, e.g. a local stand-in for tests.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// The value of the `session` cookie of a logged in browser.
+pub const COOKIE_VAR: &str = "AOC_COOKIE";
+
+/// Where puzzle pages and inputs come from.
+pub trait PuzzleSource {
+    /// The HTML of the day's puzzle page.
+    fn page(&self, year: u16, day: u8) -> Result<String, String>;
+
+    /// The day's input exactly as served, trailing newline included.
+    fn input(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+/// The Advent of Code website, or anything that serves the same paths.
+pub struct Site {
+    base_url: String,
+    cookie: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Site {
+    pub fn new(base_url: &str, cookie: Option<String>) -> Self {
+        Site {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            cookie,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    pub fn request(&self, method: &str, path: &str) -> ureq::Request {
+        let request = self.agent.request(method, &self.url(path));
+        match &self.cookie {
+            Some(cookie) => request.set("Cookie", &format!("session={cookie}")),
+            None => request,
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String, String> {
+        let url = self.url(path);
+        self.request("GET", path)
+            .call()
+            .map_err(|err| err.to_string())?
+            .into_string()
+            .map_err(|err| format!("{url}: {err}"))
+    }
+}
+
+impl PuzzleSource for Site {
+    fn page(&self, year: u16, day: u8) -> Result<String, String> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+}
+
+/// A puzzle page saved from the browser, and optionally the input next to it.
+pub struct SavedPage {
+    pub page: PathBuf,
+    pub input: Option<PathBuf>,
+}
+
+impl PuzzleSource for SavedPage {
+    fn page(&self, _year: u16, _day: u8) -> Result<String, String> {
+        fs::read_to_string(&self.page).map_err(|err| format!("{}: {err}", self.page.display()))
+    }
+
+    fn input(&self, _year: u16, _day: u8) -> Result<String, String> {
+        let path = self
+            .input
+            .as_ref()
+            .ok_or("no saved input given, pass --input-file")?;
+        fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in;
+
+    #[test]
+    fn site_test() {
+        let (base_url, server) = stand_in::serve(vec![
+            (200, "<pre>1</pre>".to_owned()),
+            (200, "3 4\n".to_owned()),
+        ]);
+        let site = Site::new(&format!("{base_url}/"), Some("abc123".to_owned()));
+        assert_eq!(site.page(2023, 7).unwrap(), "<pre>1</pre>");
+        assert_eq!(site.input(2023, 7).unwrap(), "3 4\n");
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "GET /2023/day/7 HTTP/1.1");
+        assert_eq!(requests[1].line, "GET /2023/day/7/input HTTP/1.1");
+        assert_eq!(requests[1].header("Cookie"), Some("session=abc123"));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{attempts::Verdict, site::Site};
+use scraper::{Html, Selector};
+use std::time::Duration;
+
+/// What the answer page says, or the page's text if it says something else,
+/// like the part having been solved already.
+pub fn parse_response(page: &str) -> Result<Verdict, String> {
+    let html = Html::parse_document(page);
+    let article = Selector::parse("main article").unwrap();
+    let text = match html.select(&article).next() {
+        Some(article) => article.text().collect::<String>(),
+        None => html.root_element().text().collect::<String>(),
+    };
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        Ok(if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Verdict::RateLimited(wait_left(&text).unwrap_or_default()))
+    } else {
+        Err(text)
+    }
+}
+
+/// The wait in "You have 1m 5s left to wait."
+fn wait_left(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    text[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Sends `answer` for the part and reports what the site made of it.
+pub fn submit(site: &Site, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+    let path = format!("/{year}/day/{day}/answer");
+    let page = site
+        .request("POST", &path)
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|err| err.to_string())?
+        .into_string()
+        .map_err(|err| format!("{}: {err}", site.url(&path)))?;
+    parse_response(&page).map_err(|text| format!("unexpected response: {text}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn submit_test() {
+        let too_high = page(
+            "That's not the right answer; your answer is too high.  If you're stuck, \
+             make sure you're using the full input data. <a href=\"/2023/day/7\">[Return to Day 7]</a>",
+        );
+        let too_recent = page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 5s left to wait.",
+        );
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        let right =
+            page("<span>That's the right answer!</span> You are <em>one gold star</em> closer.");
+        let (base_url, server) = stand_in::serve(vec![
+            (200, too_high),
+            (200, too_recent),
+            (200, right),
+            (200, solved),
+        ]);
+        let site = Site::new(&base_url, Some("abc123".to_owned()));
+
+        assert_eq!(submit(&site, 2023, 7, 1, "5000"), Ok(Verdict::TooHigh));
+        assert_eq!(
+            submit(&site, 2023, 7, 1, "4000"),
+            Ok(Verdict::RateLimited(Duration::from_secs(65)))
+        );
+        assert_eq!(submit(&site, 2023, 7, 1, "4000"), Ok(Verdict::Correct));
+        assert_eq!(
+            submit(&site, 2023, 7, 2, "1").unwrap_err(),
+            "unexpected response: You don't seem to be solving the right level. \
+             Did you already complete it?"
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "POST /2023/day/7/answer HTTP/1.1");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=1&answer=5000");
+        assert_eq!(requests[3].body, "level=2&answer=1");
+        assert_eq!(
+            parse_response(&page("That's not the right answer.")),
+            Ok(Verdict::Wrong)
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    answers::{self, Answer},
+    registry::Registered,
+    runner::{self, format_duration, Inputs, Outcome},
+};
+
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// Nothing recorded for this input, so there's nothing to compare against.
+    Unrecorded {
+        input_hash: String,
+    },
+    Failed(String),
+}
+
+pub struct Verification {
+    pub outcome: Outcome,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Wrong { .. } | Verdict::Failed(_))
+    }
+}
+
+pub fn judge(outcome: &Outcome, expected: Option<&Answer>, input_hash: &str) -> Verdict {
+    match (&outcome.answer, expected) {
+        (Err(err), _) => Verdict::Failed(err.clone()),
+        (Ok(_), None) => Verdict::Unrecorded {
+            input_hash: input_hash.to_owned(),
+        },
+        (Ok(answer), Some(expected)) if *answer == expected.answer => Verdict::Correct,
+        (Ok(_), Some(expected)) => Verdict::Wrong {
+            expected: expected.answer.clone(),
+        },
+    }
+}
+
+/// Runs every solution against its input and compares the result with the
+/// answer recorded for that exact input.
+pub fn verify(
+    solutions: &[&Registered],
+    inputs: &Inputs,
+    overrides: &[(String, String)],
+) -> Vec<Verification> {
+    solutions
+        .chunk_by(|a, b| (a.year, a.day) == (b.year, b.day))
+        .flat_map(|day_solutions| {
+            let (year, day) = (day_solutions[0].year, day_solutions[0].day);
+            let input = inputs.read(year, day);
+            let recorded = answers::load(year, day);
+            day_solutions
+                .iter()
+                .map(|solution| {
+                    let (input, recorded) = match (&input, &recorded) {
+                        (Ok(input), Ok(recorded)) => (input, recorded),
+                        (Err(err), _) | (_, Err(err)) => {
+                            return Verification {
+                                outcome: Outcome::failed(solution, err.clone()),
+                                verdict: Verdict::Failed(err.clone()),
+                            };
+                        }
+                    };
+                    let input_hash = answers::input_hash(input);
+                    let expected = recorded.iter().find(|answer| {
+                        answer.part == solution.part && answer.input_hash == input_hash
+                    });
+                    let outcome = runner::run_with_input(solution, input, overrides);
+                    let verdict = judge(&outcome, expected, &input_hash);
+                    Verification { outcome, verdict }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Records the answer of every part that ran fine but had nothing to compare
+/// against, returning how many were written.
+pub fn record_unrecorded(verifications: &[Verification]) -> std::io::Result<usize> {
+    let mut recorded = 0;
+    for verification in verifications {
+        if let (Verdict::Unrecorded { input_hash }, Ok(answer)) =
+            (&verification.verdict, &verification.outcome.answer)
+        {
+            answers::record(
+                verification.outcome.year,
+                &Answer {
+                    day: verification.outcome.day,
+                    part: verification.outcome.part,
+                    input_hash: input_hash.clone(),
+                    answer: answer.clone(),
+                },
+            )?;
+            recorded += 1;
+        }
+    }
+    Ok(recorded)
+}
+
+pub fn print_table(verifications: &[Verification]) {
+    let rows: Vec<(String, String)> = verifications
+        .iter()
+        .map(|verification| {
+            let answer = match &verification.outcome.answer {
+                Ok(answer) => answer.clone(),
+                Err(_) => "-".to_owned(),
+            };
+            let status = match &verification.verdict {
+                Verdict::Correct => "ok".to_owned(),
+                Verdict::Wrong { expected } => format!("MISMATCH, expected {expected}"),
+                Verdict::Unrecorded { .. } => "no recorded answer".to_owned(),
+                Verdict::Failed(err) => format!("FAILED, {err}"),
+            };
+            (answer, status)
+        })
+        .collect();
+    let answer_width = rows
+        .iter()
+        .map(|(answer, _)| answer.chars().count())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap();
+    println!(
+        "Year  Day  Part  {:<answer_width$}  {:>10}  Status",
+        "Answer", "Time"
+    );
+    for (verification, (answer, status)) in verifications.iter().zip(rows) {
+        println!(
+            "{}  {:>3}  {:>4}  {:<answer_width$}  {:>10}  {}",
+            verification.outcome.year,
+            verification.outcome.day,
+            verification.outcome.part,
+            answer,
+            format_duration(verification.outcome.elapsed),
+            status
+        );
+    }
+    let count = |f: fn(&Verdict) -> bool| {
+        verifications
+            .iter()
+            .filter(|verification| f(&verification.verdict))
+            .count()
+    };
+    println!(
+        "{} ok, {} mismatched, {} failed, {} unrecorded",
+        count(|verdict| matches!(verdict, Verdict::Correct)),
+        count(|verdict| matches!(verdict, Verdict::Wrong { .. })),
+        count(|verdict| matches!(verdict, Verdict::Failed(_))),
+        count(|verdict| matches!(verdict, Verdict::Unrecorded { .. })),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn judge_test() {
+        let outcome = |answer: Result<&str, &str>| Outcome {
+            year: 2023,
+            day: 6,
+            part: 1,
+            answer: answer.map(str::to_owned).map_err(str::to_owned),
+            elapsed: Duration::ZERO,
+        };
+        let expected = Answer {
+            day: 6,
+            part: 1,
+            input_hash: "0123456789abcdef".to_owned(),
+            answer: "288".to_owned(),
+        };
+        assert!(matches!(
+            judge(&outcome(Ok("288")), Some(&expected), "0123456789abcdef"),
+            Verdict::Correct
+        ));
+        assert!(matches!(
+            judge(&outcome(Ok("287")), Some(&expected), "0123456789abcdef"),
+            Verdict::Wrong { expected } if expected == "288"
+        ));
+        assert!(matches!(
+            judge(&outcome(Ok("288")), None, "0123456789abcdef"),
+            Verdict::Unrecorded { .. }
+        ));
+        assert!(matches!(
+            judge(
+                &outcome(Err("panicked: boom")),
+                Some(&expected),
+                "0123456789abcdef"
+            ),
+            Verdict::Failed(_)
+        ));
+    }
+}
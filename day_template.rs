@@ -0,0 +1,41 @@
+use crate::Error;
+use aoc_common::{Params, Solution};
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    todo!();
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = YEAR_NUMBER;
+    const DAY: u8 = DAY_NUMBER;
+    const PART: u8 = 1;
+    const EXAMPLE: Option<&'static str> = Some(EXAMPLE);
+
+    type Input<'a> = &'a str;
+    type Output = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<&str, Error> {
+        Ok(input)
+    }
+
+    fn solve(input: &str, _: &Params) -> Result<usize, Error> {
+        part1(input)
+    }
+}
+
+const EXAMPLE: &str = "\
+TEST_CASE";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let result = part1(EXAMPLE);
+        assert_eq!(result, Ok(TEST_RESULT));
+    }
+}